@@ -3,6 +3,8 @@ import { dlmmProgramId } from ".";
 
 export const SHARE_PRECISION = 1_000_000;
 
+export const MEMO_PROGRAM_ID = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
 export const DLMM_EVENT_AUTHORITY_PDA = PublicKey.findProgramAddressSync(
     [Buffer.from("__event_authority")],
     dlmmProgramId.PROGRAM_ID
@@ -5,6 +5,7 @@ import * as types from "../types" // eslint-disable-line @typescript-eslint/no-u
 import { PROGRAM_ID } from "../programId"
 
 export interface PendingWithdrawalFields {
+  version: number
   user: PublicKey
   strategy: PublicKey
   sharesAmount: BN
@@ -12,9 +13,11 @@ export interface PendingWithdrawalFields {
   initiationTimestamp: BN
   availableTimestamp: BN
   bump: number
+  reserved: Array<number>
 }
 
 export interface PendingWithdrawalJSON {
+  version: number
   user: string
   strategy: string
   sharesAmount: string
@@ -22,9 +25,11 @@ export interface PendingWithdrawalJSON {
   initiationTimestamp: string
   availableTimestamp: string
   bump: number
+  reserved: Array<number>
 }
 
 export class PendingWithdrawal {
+  readonly version: number
   readonly user: PublicKey
   readonly strategy: PublicKey
   readonly sharesAmount: BN
@@ -32,12 +37,14 @@ export class PendingWithdrawal {
   readonly initiationTimestamp: BN
   readonly availableTimestamp: BN
   readonly bump: number
+  readonly reserved: Array<number>
 
   static readonly discriminator = Buffer.from([
     61, 103, 179, 177, 148, 199, 63, 171,
   ])
 
   static readonly layout = borsh.struct([
+    borsh.u8("version"),
     borsh.publicKey("user"),
     borsh.publicKey("strategy"),
     borsh.u64("sharesAmount"),
@@ -45,9 +52,11 @@ export class PendingWithdrawal {
     borsh.i64("initiationTimestamp"),
     borsh.i64("availableTimestamp"),
     borsh.u8("bump"),
+    borsh.array(borsh.u8(), 64, "reserved"),
   ])
 
   constructor(fields: PendingWithdrawalFields) {
+    this.version = fields.version
     this.user = fields.user
     this.strategy = fields.strategy
     this.sharesAmount = fields.sharesAmount
@@ -55,6 +64,7 @@ export class PendingWithdrawal {
     this.initiationTimestamp = fields.initiationTimestamp
     this.availableTimestamp = fields.availableTimestamp
     this.bump = fields.bump
+    this.reserved = fields.reserved
   }
 
   static async fetch(
@@ -101,6 +111,7 @@ export class PendingWithdrawal {
     const dec = PendingWithdrawal.layout.decode(data.slice(8))
 
     return new PendingWithdrawal({
+      version: dec.version,
       user: dec.user,
       strategy: dec.strategy,
       sharesAmount: dec.sharesAmount,
@@ -108,11 +119,13 @@ export class PendingWithdrawal {
       initiationTimestamp: dec.initiationTimestamp,
       availableTimestamp: dec.availableTimestamp,
       bump: dec.bump,
+      reserved: dec.reserved,
     })
   }
 
   toJSON(): PendingWithdrawalJSON {
     return {
+      version: this.version,
       user: this.user.toString(),
       strategy: this.strategy.toString(),
       sharesAmount: this.sharesAmount.toString(),
@@ -120,11 +133,13 @@ export class PendingWithdrawal {
       initiationTimestamp: this.initiationTimestamp.toString(),
       availableTimestamp: this.availableTimestamp.toString(),
       bump: this.bump,
+      reserved: this.reserved,
     }
   }
 
   static fromJSON(obj: PendingWithdrawalJSON): PendingWithdrawal {
     return new PendingWithdrawal({
+      version: obj.version,
       user: new PublicKey(obj.user),
       strategy: new PublicKey(obj.strategy),
       sharesAmount: new BN(obj.sharesAmount),
@@ -132,6 +147,7 @@ export class PendingWithdrawal {
       initiationTimestamp: new BN(obj.initiationTimestamp),
       availableTimestamp: new BN(obj.availableTimestamp),
       bump: obj.bump,
+      reserved: obj.reserved,
     })
   }
 }
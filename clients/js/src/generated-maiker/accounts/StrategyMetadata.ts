@@ -0,0 +1,153 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface StrategyMetadataFields {
+  version: number
+  strategy: PublicKey
+  name: string
+  symbol: string
+  uri: string
+  category: types.StrategyCategoryKind
+  targetPairs: Array<PublicKey>
+  bump: number
+  reserved: Array<number>
+}
+
+export interface StrategyMetadataJSON {
+  version: number
+  strategy: string
+  name: string
+  symbol: string
+  uri: string
+  category: types.StrategyCategoryJSON
+  targetPairs: Array<string>
+  bump: number
+  reserved: Array<number>
+}
+
+export class StrategyMetadata {
+  readonly version: number
+  readonly strategy: PublicKey
+  readonly name: string
+  readonly symbol: string
+  readonly uri: string
+  readonly category: types.StrategyCategoryKind
+  readonly targetPairs: Array<PublicKey>
+  readonly bump: number
+  readonly reserved: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    232, 121, 99, 116, 234, 6, 213, 227,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u8("version"),
+    borsh.publicKey("strategy"),
+    borsh.str("name"),
+    borsh.str("symbol"),
+    borsh.str("uri"),
+    types.StrategyCategory.layout("category"),
+    borsh.vec(borsh.publicKey(), "targetPairs"),
+    borsh.u8("bump"),
+    borsh.array(borsh.u8(), 64, "reserved"),
+  ])
+
+  constructor(fields: StrategyMetadataFields) {
+    this.version = fields.version
+    this.strategy = fields.strategy
+    this.name = fields.name
+    this.symbol = fields.symbol
+    this.uri = fields.uri
+    this.category = fields.category
+    this.targetPairs = fields.targetPairs
+    this.bump = fields.bump
+    this.reserved = fields.reserved
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<StrategyMetadata | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<StrategyMetadata | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): StrategyMetadata {
+    if (!data.slice(0, 8).equals(StrategyMetadata.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = StrategyMetadata.layout.decode(data.slice(8))
+
+    return new StrategyMetadata({
+      version: dec.version,
+      strategy: dec.strategy,
+      name: dec.name,
+      symbol: dec.symbol,
+      uri: dec.uri,
+      category: types.StrategyCategory.fromDecoded(dec.category),
+      targetPairs: dec.targetPairs,
+      bump: dec.bump,
+      reserved: dec.reserved,
+    })
+  }
+
+  toJSON(): StrategyMetadataJSON {
+    return {
+      version: this.version,
+      strategy: this.strategy.toString(),
+      name: this.name,
+      symbol: this.symbol,
+      uri: this.uri,
+      category: this.category.toJSON(),
+      targetPairs: this.targetPairs.map((item) => item.toString()),
+      bump: this.bump,
+      reserved: this.reserved,
+    }
+  }
+
+  static fromJSON(obj: StrategyMetadataJSON): StrategyMetadata {
+    return new StrategyMetadata({
+      version: obj.version,
+      strategy: new PublicKey(obj.strategy),
+      name: obj.name,
+      symbol: obj.symbol,
+      uri: obj.uri,
+      category: types.StrategyCategory.fromJSON(obj.category),
+      targetPairs: obj.targetPairs.map((item) => new PublicKey(item)),
+      bump: obj.bump,
+      reserved: obj.reserved,
+    })
+  }
+}
@@ -0,0 +1,217 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface StrategyPositionFields {
+  version: number
+  strategy: PublicKey
+  position: PublicKey
+  lbPair: PublicKey
+  lowerBinId: number
+  upperBinId: number
+  value: BN
+  valueSlot: BN
+  valuationRound: BN
+  costBasisX: BN
+  costBasisY: BN
+  accruedFeeX: BN
+  accruedFeeY: BN
+  bump: number
+  derived: boolean
+  positionBump: number
+  reserved: Array<number>
+}
+
+export interface StrategyPositionJSON {
+  version: number
+  strategy: string
+  position: string
+  lbPair: string
+  lowerBinId: number
+  upperBinId: number
+  value: string
+  valueSlot: string
+  valuationRound: string
+  costBasisX: string
+  costBasisY: string
+  accruedFeeX: string
+  accruedFeeY: string
+  bump: number
+  derived: boolean
+  positionBump: number
+  reserved: Array<number>
+}
+
+export class StrategyPosition {
+  readonly version: number
+  readonly strategy: PublicKey
+  readonly position: PublicKey
+  readonly lbPair: PublicKey
+  readonly lowerBinId: number
+  readonly upperBinId: number
+  readonly value: BN
+  readonly valueSlot: BN
+  readonly valuationRound: BN
+  readonly costBasisX: BN
+  readonly costBasisY: BN
+  readonly accruedFeeX: BN
+  readonly accruedFeeY: BN
+  readonly bump: number
+  readonly derived: boolean
+  readonly positionBump: number
+  readonly reserved: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    39, 177, 202, 35, 88, 177, 104, 129,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u8("version"),
+    borsh.publicKey("strategy"),
+    borsh.publicKey("position"),
+    borsh.publicKey("lbPair"),
+    borsh.i32("lowerBinId"),
+    borsh.i32("upperBinId"),
+    borsh.u64("value"),
+    borsh.u64("valueSlot"),
+    borsh.u64("valuationRound"),
+    borsh.u64("costBasisX"),
+    borsh.u64("costBasisY"),
+    borsh.u64("accruedFeeX"),
+    borsh.u64("accruedFeeY"),
+    borsh.u8("bump"),
+    borsh.bool("derived"),
+    borsh.u8("positionBump"),
+    borsh.array(borsh.u8(), 62, "reserved"),
+  ])
+
+  constructor(fields: StrategyPositionFields) {
+    this.version = fields.version
+    this.strategy = fields.strategy
+    this.position = fields.position
+    this.lbPair = fields.lbPair
+    this.lowerBinId = fields.lowerBinId
+    this.upperBinId = fields.upperBinId
+    this.value = fields.value
+    this.valueSlot = fields.valueSlot
+    this.valuationRound = fields.valuationRound
+    this.costBasisX = fields.costBasisX
+    this.costBasisY = fields.costBasisY
+    this.accruedFeeX = fields.accruedFeeX
+    this.accruedFeeY = fields.accruedFeeY
+    this.bump = fields.bump
+    this.derived = fields.derived
+    this.positionBump = fields.positionBump
+    this.reserved = fields.reserved
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<StrategyPosition | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<StrategyPosition | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): StrategyPosition {
+    if (!data.slice(0, 8).equals(StrategyPosition.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = StrategyPosition.layout.decode(data.slice(8))
+
+    return new StrategyPosition({
+      version: dec.version,
+      strategy: dec.strategy,
+      position: dec.position,
+      lbPair: dec.lbPair,
+      lowerBinId: dec.lowerBinId,
+      upperBinId: dec.upperBinId,
+      value: dec.value,
+      valueSlot: dec.valueSlot,
+      valuationRound: dec.valuationRound,
+      costBasisX: dec.costBasisX,
+      costBasisY: dec.costBasisY,
+      accruedFeeX: dec.accruedFeeX,
+      accruedFeeY: dec.accruedFeeY,
+      bump: dec.bump,
+      derived: dec.derived,
+      positionBump: dec.positionBump,
+      reserved: dec.reserved,
+    })
+  }
+
+  toJSON(): StrategyPositionJSON {
+    return {
+      version: this.version,
+      strategy: this.strategy.toString(),
+      position: this.position.toString(),
+      lbPair: this.lbPair.toString(),
+      lowerBinId: this.lowerBinId,
+      upperBinId: this.upperBinId,
+      value: this.value.toString(),
+      valueSlot: this.valueSlot.toString(),
+      valuationRound: this.valuationRound.toString(),
+      costBasisX: this.costBasisX.toString(),
+      costBasisY: this.costBasisY.toString(),
+      accruedFeeX: this.accruedFeeX.toString(),
+      accruedFeeY: this.accruedFeeY.toString(),
+      bump: this.bump,
+      derived: this.derived,
+      positionBump: this.positionBump,
+      reserved: this.reserved,
+    }
+  }
+
+  static fromJSON(obj: StrategyPositionJSON): StrategyPosition {
+    return new StrategyPosition({
+      version: obj.version,
+      strategy: new PublicKey(obj.strategy),
+      position: new PublicKey(obj.position),
+      lbPair: new PublicKey(obj.lbPair),
+      lowerBinId: obj.lowerBinId,
+      upperBinId: obj.upperBinId,
+      value: new BN(obj.value),
+      valueSlot: new BN(obj.valueSlot),
+      valuationRound: new BN(obj.valuationRound),
+      costBasisX: new BN(obj.costBasisX),
+      costBasisY: new BN(obj.costBasisY),
+      accruedFeeX: new BN(obj.accruedFeeX),
+      accruedFeeY: new BN(obj.accruedFeeY),
+      bump: obj.bump,
+      derived: obj.derived,
+      positionBump: obj.positionBump,
+      reserved: obj.reserved,
+    })
+  }
+}
@@ -7,5 +7,15 @@ export type {
 } from "./PendingWithdrawal"
 export { StrategyConfig } from "./StrategyConfig"
 export type { StrategyConfigFields, StrategyConfigJSON } from "./StrategyConfig"
+export { StrategyMetadata } from "./StrategyMetadata"
+export type {
+  StrategyMetadataFields,
+  StrategyMetadataJSON,
+} from "./StrategyMetadata"
+export { StrategyPosition } from "./StrategyPosition"
+export type {
+  StrategyPositionFields,
+  StrategyPositionJSON,
+} from "./StrategyPosition"
 export { UserPosition } from "./UserPosition"
 export type { UserPositionFields, UserPositionJSON } from "./UserPosition"
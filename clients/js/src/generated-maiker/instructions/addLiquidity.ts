@@ -12,9 +12,12 @@ export interface AddLiquidityAccounts {
   /** The authority of the strategy */
   authority: PublicKey
   globalConfig: PublicKey
+  /** Registry entry of the strategy creator, required unless the authority is the admin */
+  strategyCreator: PublicKey
   strategy: PublicKey
   /** CPI accounts below */
   position: PublicKey
+  strategyPosition: PublicKey
   lbPair: PublicKey
   tokenXMint: PublicKey
   tokenYMint: PublicKey
@@ -48,8 +51,10 @@ export function addLiquidity(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.authority, isSigner: true, isWritable: true },
     { pubkey: accounts.globalConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyCreator, isSigner: false, isWritable: false },
     { pubkey: accounts.strategy, isSigner: false, isWritable: true },
     { pubkey: accounts.position, isSigner: false, isWritable: true },
+    { pubkey: accounts.strategyPosition, isSigner: false, isWritable: true },
     { pubkey: accounts.lbPair, isSigner: false, isWritable: true },
     { pubkey: accounts.tokenXMint, isSigner: false, isWritable: true },
     { pubkey: accounts.tokenYMint, isSigner: false, isWritable: true },
@@ -11,6 +11,8 @@ export interface ClaimFeesArgs {
 export interface ClaimFeesAccounts {
   authority: PublicKey
   globalConfig: PublicKey
+  /** Registry entry of the strategy creator, required unless the authority is the admin */
+  strategyCreator: PublicKey
   strategy: PublicKey
   strategyVaultX: PublicKey
   treasuryX: PublicKey
@@ -27,6 +29,7 @@ export function claimFees(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.authority, isSigner: true, isWritable: false },
     { pubkey: accounts.globalConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyCreator, isSigner: false, isWritable: false },
     { pubkey: accounts.strategy, isSigner: false, isWritable: true },
     { pubkey: accounts.strategyVaultX, isSigner: false, isWritable: true },
     { pubkey: accounts.treasuryX, isSigner: false, isWritable: true },
@@ -7,8 +7,11 @@ import { PROGRAM_ID } from "../programId"
 export interface ClosePositionAccounts {
   authority: PublicKey
   globalConfig: PublicKey
+  /** Registry entry of the strategy creator, required unless the authority is the admin */
+  strategyCreator: PublicKey
   strategy: PublicKey
   position: PublicKey
+  strategyPosition: PublicKey
   lbPair: PublicKey
   binArrayLower: PublicKey
   binArrayUpper: PublicKey
@@ -25,8 +28,10 @@ export function closePosition(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.authority, isSigner: true, isWritable: false },
     { pubkey: accounts.globalConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyCreator, isSigner: false, isWritable: false },
     { pubkey: accounts.strategy, isSigner: false, isWritable: true },
     { pubkey: accounts.position, isSigner: false, isWritable: true },
+    { pubkey: accounts.strategyPosition, isSigner: false, isWritable: true },
     { pubkey: accounts.lbPair, isSigner: false, isWritable: true },
     { pubkey: accounts.binArrayLower, isSigner: false, isWritable: true },
     { pubkey: accounts.binArrayUpper, isSigner: false, isWritable: true },
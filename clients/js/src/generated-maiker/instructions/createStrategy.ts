@@ -4,6 +4,10 @@ import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-esl
 import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
+export interface CreateStrategyArgs {
+  strategyId: number
+}
+
 export interface CreateStrategyAccounts {
   creator: PublicKey
   xMint: PublicKey
@@ -16,7 +20,10 @@ export interface CreateStrategyAccounts {
   systemProgram: PublicKey
 }
 
+export const layout = borsh.struct([borsh.u16("strategyId")])
+
 export function createStrategy(
+  args: CreateStrategyArgs,
   accounts: CreateStrategyAccounts,
   programId: PublicKey = PROGRAM_ID
 ) {
@@ -36,7 +43,14 @@ export function createStrategy(
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([152, 160, 107, 148, 245, 190, 127, 224])
-  const data = identifier
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      strategyId: args.strategyId,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
   const ix = new TransactionInstruction({ keys, programId, data })
   return ix
 }
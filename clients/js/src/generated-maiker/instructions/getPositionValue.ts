@@ -7,6 +7,7 @@ import { PROGRAM_ID } from "../programId"
 export interface GetPositionValueAccounts {
   strategy: PublicKey
   position: PublicKey
+  strategyPosition: PublicKey
   lbPair: PublicKey
   binArrayLower: PublicKey
   binArrayUpper: PublicKey
@@ -20,6 +21,7 @@ export function getPositionValue(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.strategy, isSigner: false, isWritable: true },
     { pubkey: accounts.position, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyPosition, isSigner: false, isWritable: true },
     { pubkey: accounts.lbPair, isSigner: false, isWritable: false },
     { pubkey: accounts.binArrayLower, isSigner: false, isWritable: false },
     { pubkey: accounts.binArrayUpper, isSigner: false, isWritable: false },
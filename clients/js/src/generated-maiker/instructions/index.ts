@@ -1,7 +1,10 @@
 export { initialize } from "./initialize"
 export type { InitializeArgs, InitializeAccounts } from "./initialize"
 export { createStrategy } from "./createStrategy"
-export type { CreateStrategyAccounts } from "./createStrategy"
+export type {
+  CreateStrategyArgs,
+  CreateStrategyAccounts,
+} from "./createStrategy"
 export { deposit } from "./deposit"
 export type { DepositArgs, DepositAccounts } from "./deposit"
 export { initiateWithdrawal } from "./initiateWithdrawal"
@@ -22,8 +25,53 @@ export { claimFees } from "./claimFees"
 export type { ClaimFeesArgs, ClaimFeesAccounts } from "./claimFees"
 export { addLiquidity } from "./addLiquidity"
 export type { AddLiquidityArgs, AddLiquidityAccounts } from "./addLiquidity"
+export { addLiquidityByStrategy } from "./addLiquidityByStrategy"
+export type {
+  AddLiquidityByStrategyArgs,
+  AddLiquidityByStrategyAccounts,
+} from "./addLiquidityByStrategy"
+export { addLiquidityByStrategy2 } from "./addLiquidityByStrategy2"
+export type {
+  AddLiquidityByStrategy2Args,
+  AddLiquidityByStrategy2Accounts,
+} from "./addLiquidityByStrategy2"
+export { addLiquidityByStrategyOneSide } from "./addLiquidityByStrategyOneSide"
+export type {
+  AddLiquidityByStrategyOneSideArgs,
+  AddLiquidityByStrategyOneSideAccounts,
+} from "./addLiquidityByStrategyOneSide"
+export { addLiquidityOneSide } from "./addLiquidityOneSide"
+export type {
+  AddLiquidityOneSideArgs,
+  AddLiquidityOneSideAccounts,
+} from "./addLiquidityOneSide"
+export { addLiquidityOneSidePrecise } from "./addLiquidityOneSidePrecise"
+export type {
+  AddLiquidityOneSidePreciseArgs,
+  AddLiquidityOneSidePreciseAccounts,
+} from "./addLiquidityOneSidePrecise"
+export { addLiquidityOneSidePrecise2 } from "./addLiquidityOneSidePrecise2"
+export type {
+  AddLiquidityOneSidePrecise2Args,
+  AddLiquidityOneSidePrecise2Accounts,
+} from "./addLiquidityOneSidePrecise2"
 export { removeLiquidity } from "./removeLiquidity"
 export type { RemoveLiquidityAccounts } from "./removeLiquidity"
+export { removeLiquidityByRange } from "./removeLiquidityByRange"
+export type {
+  RemoveLiquidityByRangeArgs,
+  RemoveLiquidityByRangeAccounts,
+} from "./removeLiquidityByRange"
+export { removeLiquidityByRange2 } from "./removeLiquidityByRange2"
+export type {
+  RemoveLiquidityByRange2Args,
+  RemoveLiquidityByRange2Accounts,
+} from "./removeLiquidityByRange2"
+export { removeLiquidityByBins } from "./removeLiquidityByBins"
+export type {
+  RemoveLiquidityByBinsArgs,
+  RemoveLiquidityByBinsAccounts,
+} from "./removeLiquidityByBins"
 export { claimFee } from "./claimFee"
 export type { ClaimFeeAccounts } from "./claimFee"
 export { closePosition } from "./closePosition"
@@ -33,9 +81,19 @@ export type {
   InitializePositionArgs,
   InitializePositionAccounts,
 } from "./initializePosition"
+export { initializePositionPda } from "./initializePositionPda"
+export type {
+  InitializePositionPdaArgs,
+  InitializePositionPdaAccounts,
+} from "./initializePositionPda"
 export { swapExactIn } from "./swapExactIn"
 export type { SwapExactInArgs, SwapExactInAccounts } from "./swapExactIn"
 export { beginSwap } from "./beginSwap"
 export type { BeginSwapArgs, BeginSwapAccounts } from "./beginSwap"
 export { endSwap } from "./endSwap"
 export type { EndSwapArgs, EndSwapAccounts } from "./endSwap"
+export { updateStrategyMetadata } from "./updateStrategyMetadata"
+export type {
+  UpdateStrategyMetadataArgs,
+  UpdateStrategyMetadataAccounts,
+} from "./updateStrategyMetadata"
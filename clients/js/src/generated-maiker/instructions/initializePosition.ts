@@ -12,9 +12,12 @@ export interface InitializePositionArgs {
 export interface InitializePositionAccounts {
   authority: PublicKey
   globalConfig: PublicKey
+  /** Registry entry of the strategy creator, required unless the authority is the admin */
+  strategyCreator: PublicKey
   strategy: PublicKey
   position: PublicKey
   lbPair: PublicKey
+  strategyPosition: PublicKey
   /** The lb_clmm program */
   lbClmmProgram: PublicKey
   eventAuthority: PublicKey
@@ -35,9 +38,11 @@ export function initializePosition(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.authority, isSigner: true, isWritable: true },
     { pubkey: accounts.globalConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyCreator, isSigner: false, isWritable: false },
     { pubkey: accounts.strategy, isSigner: false, isWritable: true },
     { pubkey: accounts.position, isSigner: true, isWritable: true },
     { pubkey: accounts.lbPair, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyPosition, isSigner: false, isWritable: true },
     { pubkey: accounts.lbClmmProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
@@ -7,10 +7,13 @@ import { PROGRAM_ID } from "../programId"
 export interface RemoveLiquidityAccounts {
   authority: PublicKey
   globalConfig: PublicKey
+  /** Registry entry of the strategy creator, required unless the authority is the admin */
+  strategyCreator: PublicKey
   strategy: PublicKey
   strategyVaultX: PublicKey
   strategyVaultY: PublicKey
   position: PublicKey
+  strategyPosition: PublicKey
   lbPair: PublicKey
   binArrayBitmapExtension: PublicKey
   reserveX: PublicKey
@@ -22,6 +25,8 @@ export interface RemoveLiquidityAccounts {
   lbClmmProgram: PublicKey
   eventAuthority: PublicKey
   tokenProgram: PublicKey
+  /** The memo program, required by remove_liquidity_by_range2 */
+  memoProgram: PublicKey
 }
 
 export function removeLiquidity(
@@ -31,10 +36,12 @@ export function removeLiquidity(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.authority, isSigner: true, isWritable: false },
     { pubkey: accounts.globalConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyCreator, isSigner: false, isWritable: false },
     { pubkey: accounts.strategy, isSigner: false, isWritable: true },
     { pubkey: accounts.strategyVaultX, isSigner: false, isWritable: true },
     { pubkey: accounts.strategyVaultY, isSigner: false, isWritable: true },
     { pubkey: accounts.position, isSigner: false, isWritable: true },
+    { pubkey: accounts.strategyPosition, isSigner: false, isWritable: true },
     { pubkey: accounts.lbPair, isSigner: false, isWritable: true },
     {
       pubkey: accounts.binArrayBitmapExtension,
@@ -50,6 +57,7 @@ export function removeLiquidity(
     { pubkey: accounts.lbClmmProgram, isSigner: false, isWritable: true },
     { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.memoProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([80, 85, 209, 72, 24, 206, 177, 108])
   const data = identifier
@@ -0,0 +1,88 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RemoveLiquidityByRange2Args {
+  fromBinId: number
+  toBinId: number
+  bpsToRemove: number
+}
+
+export interface RemoveLiquidityByRange2Accounts {
+  authority: PublicKey
+  globalConfig: PublicKey
+  /** Registry entry of the strategy creator, required unless the authority is the admin */
+  strategyCreator: PublicKey
+  strategy: PublicKey
+  strategyVaultX: PublicKey
+  strategyVaultY: PublicKey
+  position: PublicKey
+  strategyPosition: PublicKey
+  lbPair: PublicKey
+  binArrayBitmapExtension: PublicKey
+  reserveX: PublicKey
+  reserveY: PublicKey
+  tokenXMint: PublicKey
+  tokenYMint: PublicKey
+  binArrayLower: PublicKey
+  binArrayUpper: PublicKey
+  lbClmmProgram: PublicKey
+  eventAuthority: PublicKey
+  tokenProgram: PublicKey
+  /** The memo program, required by remove_liquidity_by_range2 */
+  memoProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.i32("fromBinId"),
+  borsh.i32("toBinId"),
+  borsh.u16("bpsToRemove"),
+])
+
+export function removeLiquidityByRange2(
+  args: RemoveLiquidityByRange2Args,
+  accounts: RemoveLiquidityByRange2Accounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.globalConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyCreator, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategy, isSigner: false, isWritable: true },
+    { pubkey: accounts.strategyVaultX, isSigner: false, isWritable: true },
+    { pubkey: accounts.strategyVaultY, isSigner: false, isWritable: true },
+    { pubkey: accounts.position, isSigner: false, isWritable: true },
+    { pubkey: accounts.strategyPosition, isSigner: false, isWritable: true },
+    { pubkey: accounts.lbPair, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.binArrayBitmapExtension,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.reserveX, isSigner: false, isWritable: true },
+    { pubkey: accounts.reserveY, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenXMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenYMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.binArrayLower, isSigner: false, isWritable: true },
+    { pubkey: accounts.binArrayUpper, isSigner: false, isWritable: true },
+    { pubkey: accounts.lbClmmProgram, isSigner: false, isWritable: true },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.memoProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([204, 2, 195, 145, 53, 145, 145, 205])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      fromBinId: args.fromBinId,
+      toBinId: args.toBinId,
+      bpsToRemove: args.bpsToRemove,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,48 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UpdateStrategyMetadataArgs {
+  strategyMetadataArgs: types.StrategyMetadataArgsFields
+}
+
+export interface UpdateStrategyMetadataAccounts {
+  authority: PublicKey
+  globalConfig: PublicKey
+  strategy: PublicKey
+  strategyMetadata: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.StrategyMetadataArgs.layout("strategyMetadataArgs"),
+])
+
+export function updateStrategyMetadata(
+  args: UpdateStrategyMetadataArgs,
+  accounts: UpdateStrategyMetadataAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.globalConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategy, isSigner: false, isWritable: false },
+    { pubkey: accounts.strategyMetadata, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([239, 135, 77, 247, 235, 180, 227, 124])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      strategyMetadataArgs: types.StrategyMetadataArgs.toEncodable(
+        args.strategyMetadataArgs
+      ),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
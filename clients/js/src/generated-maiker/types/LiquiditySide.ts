@@ -0,0 +1,90 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface XJSON {
+  kind: "X"
+}
+
+export class X {
+  static readonly discriminator = 0
+  static readonly kind = "X"
+  readonly discriminator = 0
+  readonly kind = "X"
+
+  toJSON(): XJSON {
+    return {
+      kind: "X",
+    }
+  }
+
+  toEncodable() {
+    return {
+      X: {},
+    }
+  }
+}
+
+export interface YJSON {
+  kind: "Y"
+}
+
+export class Y {
+  static readonly discriminator = 1
+  static readonly kind = "Y"
+  readonly discriminator = 1
+  readonly kind = "Y"
+
+  toJSON(): YJSON {
+    return {
+      kind: "Y",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Y: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.LiquiditySideKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("X" in obj) {
+    return new X()
+  }
+  if ("Y" in obj) {
+    return new Y()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.LiquiditySideJSON
+): types.LiquiditySideKind {
+  switch (obj.kind) {
+    case "X": {
+      return new X()
+    }
+    case "Y": {
+      return new Y()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "X"),
+    borsh.struct([], "Y"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
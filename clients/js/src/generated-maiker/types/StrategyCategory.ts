@@ -0,0 +1,150 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface OtherJSON {
+  kind: "Other"
+}
+
+export class Other {
+  static readonly discriminator = 0
+  static readonly kind = "Other"
+  readonly discriminator = 0
+  readonly kind = "Other"
+
+  toJSON(): OtherJSON {
+    return {
+      kind: "Other",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Other: {},
+    }
+  }
+}
+
+export interface StableJSON {
+  kind: "Stable"
+}
+
+export class Stable {
+  static readonly discriminator = 1
+  static readonly kind = "Stable"
+  readonly discriminator = 1
+  readonly kind = "Stable"
+
+  toJSON(): StableJSON {
+    return {
+      kind: "Stable",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Stable: {},
+    }
+  }
+}
+
+export interface CorrelatedJSON {
+  kind: "Correlated"
+}
+
+export class Correlated {
+  static readonly discriminator = 2
+  static readonly kind = "Correlated"
+  readonly discriminator = 2
+  readonly kind = "Correlated"
+
+  toJSON(): CorrelatedJSON {
+    return {
+      kind: "Correlated",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Correlated: {},
+    }
+  }
+}
+
+export interface VolatileJSON {
+  kind: "Volatile"
+}
+
+export class Volatile {
+  static readonly discriminator = 3
+  static readonly kind = "Volatile"
+  readonly discriminator = 3
+  readonly kind = "Volatile"
+
+  toJSON(): VolatileJSON {
+    return {
+      kind: "Volatile",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Volatile: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.StrategyCategoryKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Other" in obj) {
+    return new Other()
+  }
+  if ("Stable" in obj) {
+    return new Stable()
+  }
+  if ("Correlated" in obj) {
+    return new Correlated()
+  }
+  if ("Volatile" in obj) {
+    return new Volatile()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.StrategyCategoryJSON
+): types.StrategyCategoryKind {
+  switch (obj.kind) {
+    case "Other": {
+      return new Other()
+    }
+    case "Stable": {
+      return new Stable()
+    }
+    case "Correlated": {
+      return new Correlated()
+    }
+    case "Volatile": {
+      return new Volatile()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Other"),
+    borsh.struct([], "Stable"),
+    borsh.struct([], "Correlated"),
+    borsh.struct([], "Volatile"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
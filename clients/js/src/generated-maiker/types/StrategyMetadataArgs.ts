@@ -0,0 +1,94 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface StrategyMetadataArgsFields {
+  name: string
+  symbol: string
+  uri: string
+  category: types.StrategyCategoryKind
+  targetPairs: Array<PublicKey>
+}
+
+export interface StrategyMetadataArgsJSON {
+  name: string
+  symbol: string
+  uri: string
+  category: types.StrategyCategoryJSON
+  targetPairs: Array<string>
+}
+
+export class StrategyMetadataArgs {
+  readonly name: string
+  readonly symbol: string
+  readonly uri: string
+  readonly category: types.StrategyCategoryKind
+  readonly targetPairs: Array<PublicKey>
+
+  constructor(fields: StrategyMetadataArgsFields) {
+    this.name = fields.name
+    this.symbol = fields.symbol
+    this.uri = fields.uri
+    this.category = fields.category
+    this.targetPairs = fields.targetPairs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.str("name"),
+        borsh.str("symbol"),
+        borsh.str("uri"),
+        types.StrategyCategory.layout("category"),
+        borsh.vec(borsh.publicKey(), "targetPairs"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new StrategyMetadataArgs({
+      name: obj.name,
+      symbol: obj.symbol,
+      uri: obj.uri,
+      category: types.StrategyCategory.fromDecoded(obj.category),
+      targetPairs: obj.targetPairs,
+    })
+  }
+
+  static toEncodable(fields: StrategyMetadataArgsFields) {
+    return {
+      name: fields.name,
+      symbol: fields.symbol,
+      uri: fields.uri,
+      category: fields.category.toEncodable(),
+      targetPairs: fields.targetPairs,
+    }
+  }
+
+  toJSON(): StrategyMetadataArgsJSON {
+    return {
+      name: this.name,
+      symbol: this.symbol,
+      uri: this.uri,
+      category: this.category.toJSON(),
+      targetPairs: this.targetPairs.map((item) => item.toString()),
+    }
+  }
+
+  static fromJSON(obj: StrategyMetadataArgsJSON): StrategyMetadataArgs {
+    return new StrategyMetadataArgs({
+      name: obj.name,
+      symbol: obj.symbol,
+      uri: obj.uri,
+      category: types.StrategyCategory.fromJSON(obj.category),
+      targetPairs: obj.targetPairs.map((item) => new PublicKey(item)),
+    })
+  }
+
+  toEncodable() {
+    return StrategyMetadataArgs.toEncodable(this)
+  }
+}
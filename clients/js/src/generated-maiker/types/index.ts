@@ -1,21 +1,107 @@
 import * as Rounding from "./Rounding"
+import * as StrategyCategory from "./StrategyCategory"
+import * as StrategyType from "./StrategyType"
+import * as LiquiditySide from "./LiquiditySide"
 
 export { BinLiquidityDistributionByWeight } from "./BinLiquidityDistributionByWeight"
 export type {
   BinLiquidityDistributionByWeightFields,
   BinLiquidityDistributionByWeightJSON,
 } from "./BinLiquidityDistributionByWeight"
+export { BinLiquidityReduction } from "./BinLiquidityReduction"
+export type {
+  BinLiquidityReductionFields,
+  BinLiquidityReductionJSON,
+} from "./BinLiquidityReduction"
 export { LiquidityParameterByWeight } from "./LiquidityParameterByWeight"
 export type {
   LiquidityParameterByWeightFields,
   LiquidityParameterByWeightJSON,
 } from "./LiquidityParameterByWeight"
+export { StrategyParameters } from "./StrategyParameters"
+export type {
+  StrategyParametersFields,
+  StrategyParametersJSON,
+} from "./StrategyParameters"
+export { LiquidityParameterByStrategy } from "./LiquidityParameterByStrategy"
+export type {
+  LiquidityParameterByStrategyFields,
+  LiquidityParameterByStrategyJSON,
+} from "./LiquidityParameterByStrategy"
+export { LiquidityParameterByStrategyOneSide } from "./LiquidityParameterByStrategyOneSide"
+export type {
+  LiquidityParameterByStrategyOneSideFields,
+  LiquidityParameterByStrategyOneSideJSON,
+} from "./LiquidityParameterByStrategyOneSide"
+export { CompressedBinDepositAmount } from "./CompressedBinDepositAmount"
+export type {
+  CompressedBinDepositAmountFields,
+  CompressedBinDepositAmountJSON,
+} from "./CompressedBinDepositAmount"
+export { LiquidityOneSideParameter } from "./LiquidityOneSideParameter"
+export type {
+  LiquidityOneSideParameterFields,
+  LiquidityOneSideParameterJSON,
+} from "./LiquidityOneSideParameter"
+export { AddLiquiditySingleSidePreciseParameter } from "./AddLiquiditySingleSidePreciseParameter"
+export type {
+  AddLiquiditySingleSidePreciseParameterFields,
+  AddLiquiditySingleSidePreciseParameterJSON,
+} from "./AddLiquiditySingleSidePreciseParameter"
+export { AddLiquiditySingleSidePreciseParameter2 } from "./AddLiquiditySingleSidePreciseParameter2"
+export type {
+  AddLiquiditySingleSidePreciseParameter2Fields,
+  AddLiquiditySingleSidePreciseParameter2JSON,
+} from "./AddLiquiditySingleSidePreciseParameter2"
 export { GlobalConfigArgs } from "./GlobalConfigArgs"
 export type {
   GlobalConfigArgsFields,
   GlobalConfigArgsJSON,
 } from "./GlobalConfigArgs"
+export { StrategyMetadataArgs } from "./StrategyMetadataArgs"
+export type {
+  StrategyMetadataArgsFields,
+  StrategyMetadataArgsJSON,
+} from "./StrategyMetadataArgs"
 export { Rounding }
 
 export type RoundingKind = Rounding.Up | Rounding.Down
 export type RoundingJSON = Rounding.UpJSON | Rounding.DownJSON
+export { StrategyCategory }
+
+export type StrategyCategoryKind =
+  | StrategyCategory.Other
+  | StrategyCategory.Stable
+  | StrategyCategory.Correlated
+  | StrategyCategory.Volatile
+export type StrategyCategoryJSON =
+  | StrategyCategory.OtherJSON
+  | StrategyCategory.StableJSON
+  | StrategyCategory.CorrelatedJSON
+  | StrategyCategory.VolatileJSON
+export { StrategyType }
+
+export type StrategyTypeKind =
+  | StrategyType.SpotOneSide
+  | StrategyType.CurveOneSide
+  | StrategyType.BidAskOneSide
+  | StrategyType.SpotBalanced
+  | StrategyType.CurveBalanced
+  | StrategyType.BidAskBalanced
+  | StrategyType.SpotImBalanced
+  | StrategyType.CurveImBalanced
+  | StrategyType.BidAskImBalanced
+export type StrategyTypeJSON =
+  | StrategyType.SpotOneSideJSON
+  | StrategyType.CurveOneSideJSON
+  | StrategyType.BidAskOneSideJSON
+  | StrategyType.SpotBalancedJSON
+  | StrategyType.CurveBalancedJSON
+  | StrategyType.BidAskBalancedJSON
+  | StrategyType.SpotImBalancedJSON
+  | StrategyType.CurveImBalancedJSON
+  | StrategyType.BidAskImBalancedJSON
+export { LiquiditySide }
+
+export type LiquiditySideKind = LiquiditySide.X | LiquiditySide.Y
+export type LiquiditySideJSON = LiquiditySide.XJSON | LiquiditySide.YJSON
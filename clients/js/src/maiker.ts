@@ -24,7 +24,7 @@ import * as maikerInstructions from './generated-maiker/instructions';
 import { PROGRAM_ID as maikerProgramId } from './generated-maiker/programId';
 import * as dlmm from './generated-dlmm/accounts';
 import { PROGRAM_ID as dlmmProgramId } from './generated-dlmm/programId';
-import { DLMM_EVENT_AUTHORITY_PDA, SHARE_PRECISION } from './constants';
+import { DLMM_EVENT_AUTHORITY_PDA, MEMO_PROGRAM_ID, SHARE_PRECISION } from './constants';
 import { getOrCreateBinArraysInstructions } from './meteora/utils';
 import {
   StrategyValue,
@@ -34,13 +34,16 @@ import {
   DepositParams,
   WithdrawParams,
   PositionLiquidityParams,
+  PositionOneSideLiquidityParams,
+  PositionStrategyLiquidityParams,
+  PositionStrategyOneSideLiquidityParams,
   StrategySetupParams,
   PositionInfo,
   PositionBinData,
   PositionData,
   BinLiquidity
 } from './types';
-import { deriveGlobalConfig, derivePendingWithdrawal, deriveStrategy, deriveUserPosition } from './utils';
+import { deriveDlmmPosition, deriveGlobalConfig, derivePendingWithdrawal, deriveStrategy, deriveStrategyCreator, deriveStrategyPosition, deriveUserPosition } from './utils';
 import { chunkedGetMultipleAccountInfos, getOrCreateATAInstruction, getPricePerLamport, mulShr, Rounding } from './helpers';
 
 /**
@@ -71,6 +74,9 @@ export class MaikerSDK {
   /** Map of position addresses to their info */
   public positions: Map<string, PositionInfo> = new Map();
 
+  /** Addresses of the DLMM positions tracked by the strategy */
+  public positionPubkeys: PublicKey[] = [];
+
   /** Map of lb pair addresses to their info */
   public lbPairs: Map<string, dlmm.lbPair> = new Map();
 
@@ -160,10 +166,10 @@ export class MaikerSDK {
     connection: Connection,
     params: StrategySetupParams
   ): Promise<TransactionInstruction[]> {
-    const { creator, xMint, yMint } = params;
+    const { creator, xMint, yMint, strategyId = 0 } = params;
 
     // Find strategy PDA
-    const strategy = deriveStrategy(creator, xMint, yMint);
+    const strategy = deriveStrategy(creator, xMint, yMint, strategyId);
 
     const preIxs: TransactionInstruction[] = [];
 
@@ -179,6 +185,7 @@ export class MaikerSDK {
 
     // Create instruction
     const createStrategyIx = maikerInstructions.createStrategy(
+      { strategyId },
       {
         creator,
         xMint,
@@ -207,20 +214,34 @@ export class MaikerSDK {
     await this.fetchPositions();
   }
 
+  /**
+   * Fetches the DLMM positions of the strategy from their StrategyPosition accounts
+   */
+  public async fetchPositionPubkeys(): Promise<PublicKey[]> {
+    const accounts = await this.connection.getProgramAccounts(maikerProgramId, {
+      filters: [
+        { dataSize: 8 + maiker.StrategyPosition.layout.span },
+        // The strategy follows the discriminator and the version
+        { memcmp: { offset: 9, bytes: this.strategy.toBase58() } },
+      ],
+    });
+
+    return accounts.map(({ account }) => maiker.StrategyPosition.decode(account.data).position);
+  }
+
   public async fetchPositions(): Promise<Map<string, PositionInfo>> {
     const binArrayPubkeySetV2 = new Set<string>();
     const lbPairSetV2 = new Set<string>();
 
-    // Filter out default positions
-    const strategyPositions = this.strategyAcc.positions.filter((position) => !position.equals(PublicKey.default));
+    this.positionPubkeys = await this.fetchPositionPubkeys();
 
     const strategyPositionInfos = await chunkedGetMultipleAccountInfos(this.connection, [
-      ...strategyPositions
+      ...this.positionPubkeys
     ]);
 
     const positionsV2 = strategyPositionInfos.map((accInfo, idx) => ({
       account: dlmm.positionV2.decode(accInfo.data),
-      publicKey: this.strategyAcc.positions[idx]
+      publicKey: this.positionPubkeys[idx]
     }));
 
     positionsV2.forEach(({ account: { upperBinId, lowerBinId, lbPair } }) => {
@@ -590,6 +611,7 @@ export class MaikerSDK {
       {
         authority,
         globalConfig: this.globalConfig,
+        strategyCreator: this.getStrategyCreatorAccount(authority),
         strategy: this.strategy,
         lbPair,
         binArrayBitmapExtension: dlmmProgramId, // We know it's not required here in test
@@ -636,9 +658,11 @@ export class MaikerSDK {
       {
         authority,
         globalConfig: this.globalConfig,
+        strategyCreator: this.getStrategyCreatorAccount(authority),
         strategy: this.strategy,
         position,
         lbPair,
+        strategyPosition: deriveStrategyPosition(this.strategy, position),
         lbClmmProgram: dlmmProgramId,
         eventAuthority: DLMM_EVENT_AUTHORITY_PDA,
         systemProgram: SystemProgram.programId,
@@ -647,6 +671,43 @@ export class MaikerSDK {
     );
   }
 
+  /**
+   * Creates instruction to initialize a position PDA for strategy, no position keypair has to sign
+   */
+  public createInitializePositionPdaInstruction(
+    params: {
+      lbPair: PublicKey,
+      authority: PublicKey,
+      lowerBinId: number,
+      width: number,
+    }
+  ): { instruction: TransactionInstruction, position: PublicKey } {
+    const { lbPair, authority, lowerBinId, width } = params;
+    const position = deriveDlmmPosition(this.strategy, lbPair, lowerBinId, width);
+
+    const instruction = maikerInstructions.initializePositionPda(
+      {
+        lowerBinId,
+        width,
+      },
+      {
+        authority,
+        globalConfig: this.globalConfig,
+        strategyCreator: this.getStrategyCreatorAccount(authority),
+        strategy: this.strategy,
+        position,
+        lbPair,
+        strategyPosition: deriveStrategyPosition(this.strategy, position),
+        lbClmmProgram: dlmmProgramId,
+        eventAuthority: DLMM_EVENT_AUTHORITY_PDA,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      }
+    );
+
+    return { instruction, position };
+  }
+
   /**
    * Creates instruction to add liquidity to position
    */
@@ -697,66 +758,121 @@ export class MaikerSDK {
     const lowerBinId = Math.min(...binLiquidityDist.map(dist => dist.binId));
     const upperBinId = Math.max(...binLiquidityDist.map(dist => dist.binId));
 
-    // Check bin arrays
-    const preInstructions: TransactionInstruction[] = [];
+    const { accounts, preInstructions } = await this.getAddLiquidityAccounts(
+      authority,
+      position,
+      lbPair,
+      lbPairAcc,
+      lowerBinId,
+      upperBinId
+    );
 
-    const lowerBinArrayIndex = binIdToBinArrayIndex(new BN(lowerBinId));
-    const upperBinArrayIndex = BN.max(
-      lowerBinArrayIndex.add(new BN(1)),
-      binIdToBinArrayIndex(new BN(upperBinId))
+    // Create add liquidity instruction
+    const instruction = maikerInstructions.addLiquidity(
+      {
+        liquidityParameter: liquidityParams,
+      },
+      accounts
     );
 
-    // Get or create bin arrays
-    const { instructions, lowerBinArray, upperBinArray } = await getOrCreateBinArraysInstructions(
-      this.connection,
+    return {
+      instruction,
+      preInstructions
+    };
+  }
+
+  /**
+   * Creates instruction to add liquidity shaped by a DLMM strategy to position
+   */
+  public async createAddLiquidityByStrategyInstruction(
+    params: PositionStrategyLiquidityParams
+  ): Promise<{
+    instruction: TransactionInstruction,
+    preInstructions: TransactionInstruction[]
+  }> {
+    const { authority, position, totalXAmount, totalYAmount, minBinId, maxBinId, strategyType, lbPair } = params;
+
+    const lbPairAcc = params.lbPairAcc ?? this.lbPairs.get(lbPair.toBase58());
+
+    if (!lbPairAcc) {
+      throw new Error("LB Pair not found");
+    }
+
+    const { accounts, preInstructions } = await this.getAddLiquidityAccounts(
+      authority,
+      position,
       lbPair,
-      new BN(lowerBinArrayIndex),
-      new BN(upperBinArrayIndex),
-      authority
+      lbPairAcc,
+      minBinId,
+      maxBinId
     );
 
-    if (instructions.length > 0) {
-      preInstructions.push(...instructions);
-    }
+    const instruction = maikerInstructions.addLiquidityByStrategy(
+      {
+        liquidityParameter: {
+          amountX: totalXAmount,
+          amountY: totalYAmount,
+          activeId: lbPairAcc.activeId,
+          maxActiveBinSlippage: 0,
+          strategyParameters: {
+            minBinId,
+            maxBinId,
+            strategyType,
+            parameteres: new Array(64).fill(0),
+          },
+        },
+      },
+      accounts
+    );
 
-    // Check if extension is required
-    const useExtension =
-      isOverflowDefaultBinArrayBitmap(lowerBinArrayIndex) ||
-      isOverflowDefaultBinArrayBitmap(upperBinArrayIndex);
+    return {
+      instruction,
+      preInstructions
+    };
+  }
 
-    const binArrayBitmapExtension = useExtension
-      ? deriveBinArrayBitmapExtension(lbPair, dlmmProgramId)[0]
-      : null;
+  /**
+   * Creates instruction to add liquidity shaped by a DLMM strategy to position, supporting Token-2022 pairs
+   */
+  public async createAddLiquidityByStrategy2Instruction(
+    params: PositionStrategyLiquidityParams
+  ): Promise<{
+    instruction: TransactionInstruction,
+    preInstructions: TransactionInstruction[]
+  }> {
+    const { authority, position, totalXAmount, totalYAmount, minBinId, maxBinId, strategyType, lbPair } = params;
 
+    const lbPairAcc = params.lbPairAcc ?? this.lbPairs.get(lbPair.toBase58());
 
-    // Check if the token order is reversed
-    const isReversed = !this.xMint.address.equals(lbPairAcc.tokenXMint);
+    if (!lbPairAcc) {
+      throw new Error("LB Pair not found");
+    }
 
-    // Create add liquidity instruction
-    const instruction = maikerInstructions.addLiquidity(
+    const { accounts, preInstructions } = await this.getAddLiquidityAccounts(
+      authority,
+      position,
+      lbPair,
+      lbPairAcc,
+      minBinId,
+      maxBinId
+    );
+
+    const instruction = maikerInstructions.addLiquidityByStrategy2(
       {
-        liquidityParameter: liquidityParams,
+        liquidityParameter: {
+          amountX: totalXAmount,
+          amountY: totalYAmount,
+          activeId: lbPairAcc.activeId,
+          maxActiveBinSlippage: 0,
+          strategyParameters: {
+            minBinId,
+            maxBinId,
+            strategyType,
+            parameteres: new Array(64).fill(0),
+          },
+        },
       },
-      {
-        position,
-        authority,
-        globalConfig: this.globalConfig,
-        strategy: this.strategy,
-        lbPair,
-        tokenXMint: lbPairAcc.tokenXMint,
-        tokenYMint: lbPairAcc.tokenYMint,
-        strategyVaultX: isReversed ? this.strategyAcc.yVault : this.strategyAcc.xVault,
-        strategyVaultY: isReversed ? this.strategyAcc.xVault : this.strategyAcc.yVault,
-        reserveX: lbPairAcc.reserveX,
-        reserveY: lbPairAcc.reserveY,
-        binArrayLower: lowerBinArray,
-        binArrayUpper: upperBinArray,
-        binArrayBitmapExtension: binArrayBitmapExtension || dlmmProgramId,
-        lbClmmProgram: dlmmProgramId,
-        eventAuthority: DLMM_EVENT_AUTHORITY_PDA,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      }
+      accounts
     );
 
     return {
@@ -766,68 +882,180 @@ export class MaikerSDK {
   }
 
   /**
-   * Creates instruction to remove liquidity from position
+   * Creates instruction to add liquidity shaped by a DLMM strategy from one side of the pair to position
    */
-  public createRemoveLiquidityInstruction(
-    params: {
-      authority: PublicKey,
-      position: PublicKey,
-    }
-  ): TransactionInstruction {
-    const positionInfo = this.positions.get(params.position.toBase58());
+  public async createAddLiquidityByStrategyOneSideInstruction(
+    params: PositionStrategyOneSideLiquidityParams
+  ): Promise<{
+    instruction: TransactionInstruction,
+    preInstructions: TransactionInstruction[]
+  }> {
+    const { authority, position, side, amount, minBinId, maxBinId, strategyType, lbPair } = params;
 
-    if (!positionInfo) {
-      throw new Error("Position not found");
+    const lbPairAcc = params.lbPairAcc ?? this.lbPairs.get(lbPair.toBase58());
+
+    if (!lbPairAcc) {
+      throw new Error("LB Pair not found");
     }
 
-    const lbPairAcc = this.lbPairs.get(positionInfo.lbPair.toBase58());
+    const { accounts, preInstructions } = await this.getAddLiquidityAccounts(
+      authority,
+      position,
+      lbPair,
+      lbPairAcc,
+      minBinId,
+      maxBinId
+    );
+
+    const instruction = maikerInstructions.addLiquidityByStrategyOneSide(
+      {
+        side,
+        liquidityParameter: {
+          amount,
+          activeId: lbPairAcc.activeId,
+          maxActiveBinSlippage: 0,
+          strategyParameters: {
+            minBinId,
+            maxBinId,
+            strategyType,
+            parameteres: new Array(64).fill(0),
+          },
+        },
+      },
+      accounts
+    );
+
+    return {
+      instruction,
+      preInstructions
+    };
+  }
+
+  /**
+   * Creates instruction to add liquidity from one side of the pair to position,
+   * e.g. to deploy the idle Y balance below the active bin
+   */
+  public async createAddLiquidityOneSideInstruction(
+    params: PositionOneSideLiquidityParams
+  ): Promise<{
+    instruction: TransactionInstruction,
+    preInstructions: TransactionInstruction[]
+  }> {
+    const { authority, position, side, amount, binLiquidityDist, lbPair } = params;
+
+    const lbPairAcc = params.lbPairAcc ?? this.lbPairs.get(lbPair.toBase58());
 
     if (!lbPairAcc) {
       throw new Error("LB Pair not found");
     }
 
-    const lowerBinArrayIndex = binIdToBinArrayIndex(new BN(positionInfo?.positionData?.lowerBinId ?? 0));
-    const upperBinArrayIndex = BN.max(
-      lowerBinArrayIndex.add(new BN(1)),
-      binIdToBinArrayIndex(new BN(positionInfo?.positionData?.upperBinId ?? 0))
-    );
+    if (binLiquidityDist.length === 0) {
+      throw new Error("No liquidity to add");
+    }
 
-    const [lowerBinArrayPubKey] = deriveBinArray(
-      positionInfo.lbPair,
-      lowerBinArrayIndex,
-      dlmmProgramId
+    const { accounts, preInstructions } = await this.getAddLiquidityAccounts(
+      authority,
+      position,
+      lbPair,
+      lbPairAcc,
+      Math.min(...binLiquidityDist.map(dist => dist.binId)),
+      Math.max(...binLiquidityDist.map(dist => dist.binId))
     );
-    const [upperBinArrayPubKey] = deriveBinArray(
-      positionInfo.lbPair,
-      upperBinArrayIndex,
-      dlmmProgramId
+
+    const instruction = maikerInstructions.addLiquidityOneSide(
+      {
+        side,
+        liquidityParameter: {
+          amount,
+          activeId: lbPairAcc.activeId,
+          maxActiveBinSlippage: 0,
+          binLiquidityDist,
+        },
+      },
+      accounts
     );
 
-    const isReversed = !this.xMint.address.equals(lbPairAcc.tokenXMint);
+    return {
+      instruction,
+      preInstructions
+    };
+  }
 
-    const withdrawLiquidityIx = maikerInstructions.removeLiquidity(
+  /**
+   * Creates instruction to remove liquidity from position
+   */
+  public createRemoveLiquidityInstruction(
+    params: {
+      authority: PublicKey,
+      position: PublicKey,
+    }
+  ): TransactionInstruction {
+    return maikerInstructions.removeLiquidity(
+      this.getRemoveLiquidityAccounts(params.authority, params.position),
+    )
+  }
+
+  /**
+   * Creates instruction to remove a share of the liquidity of a bin range of a position
+   */
+  public createRemoveLiquidityByRangeInstruction(
+    params: {
+      authority: PublicKey,
+      position: PublicKey,
+      fromBinId: number,
+      toBinId: number,
+      bpsToRemove: number,
+    }
+  ): TransactionInstruction {
+    return maikerInstructions.removeLiquidityByRange(
       {
-        authority: params.authority,
-        globalConfig: this.globalConfig,
-        strategy: this.strategy,
-        strategyVaultX: isReversed ? this.strategyAcc.yVault : this.strategyAcc.xVault,
-        strategyVaultY: isReversed ? this.strategyAcc.xVault : this.strategyAcc.yVault,
-        position: params.position,
-        lbPair: positionInfo.lbPair,
-        binArrayBitmapExtension: maikerProgramId, // For testing we know no binArraybitmap extension is required
-        reserveX: lbPairAcc.reserveX,
-        reserveY: lbPairAcc.reserveY,
-        tokenXMint: lbPairAcc.tokenXMint,
-        tokenYMint: lbPairAcc.tokenYMint,
-        binArrayLower: lowerBinArrayPubKey,
-        binArrayUpper: upperBinArrayPubKey,
-        lbClmmProgram: new PublicKey(LBCLMM_PROGRAM_IDS["mainnet-beta"]),
-        eventAuthority: DLMM_EVENT_AUTHORITY_PDA,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        fromBinId: params.fromBinId,
+        toBinId: params.toBinId,
+        bpsToRemove: params.bpsToRemove,
       },
+      this.getRemoveLiquidityAccounts(params.authority, params.position),
     )
+  }
 
-    return withdrawLiquidityIx
+  /**
+   * Creates instruction to remove a share of the liquidity of a bin range of a position, supporting Token-2022 pairs
+   */
+  public createRemoveLiquidityByRange2Instruction(
+    params: {
+      authority: PublicKey,
+      position: PublicKey,
+      fromBinId: number,
+      toBinId: number,
+      bpsToRemove: number,
+    }
+  ): TransactionInstruction {
+    return maikerInstructions.removeLiquidityByRange2(
+      {
+        fromBinId: params.fromBinId,
+        toBinId: params.toBinId,
+        bpsToRemove: params.bpsToRemove,
+      },
+      {
+        ...this.getRemoveLiquidityAccounts(params.authority, params.position),
+        memoProgram: MEMO_PROGRAM_ID,
+      },
+    )
+  }
+
+  /**
+   * Creates instruction to remove a share of the liquidity of each listed bin of a position
+   */
+  public createRemoveLiquidityByBinsInstruction(
+    params: {
+      authority: PublicKey,
+      position: PublicKey,
+      binLiquidityRemoval: Array<{ binId: number, bpsToRemove: number }>,
+    }
+  ): TransactionInstruction {
+    return maikerInstructions.removeLiquidityByBins(
+      { binLiquidityRemoval: params.binLiquidityRemoval },
+      this.getRemoveLiquidityAccounts(params.authority, params.position),
+    )
   }
 
   public createMeteoraClaimFeesInstruction(
@@ -878,10 +1106,12 @@ export class MaikerSDK {
       {
         authority,
         globalConfig: this.globalConfig,
+        strategyCreator: this.getStrategyCreatorAccount(authority),
         strategy: this.strategy,
         strategyVaultX: isReversed ? this.strategyAcc.yVault : this.strategyAcc.xVault,
         strategyVaultY: isReversed ? this.strategyAcc.xVault : this.strategyAcc.yVault,
         position,
+        strategyPosition: deriveStrategyPosition(this.strategy, position),
         lbPair: positionInfo.lbPair,
         binArrayLower: lowerBinArrayPubKey,
         binArrayUpper: upperBinArrayPubKey,
@@ -937,12 +1167,14 @@ export class MaikerSDK {
       {
         authority,
         globalConfig: this.globalConfig,
+        strategyCreator: this.getStrategyCreatorAccount(authority),
         strategy: this.strategy,
         position,
+        strategyPosition: deriveStrategyPosition(this.strategy, position),
         lbPair: positionInfo.lbPair,
         binArrayLower: lowerBinArrayPubKey,
         binArrayUpper: upperBinArrayPubKey,
-        rentReceiver: authority,
+        rentReceiver: this.strategyAcc.creator,
         lbClmmProgram: dlmmProgramId,
         eventAuthority: DLMM_EVENT_AUTHORITY_PDA,
       }
@@ -966,6 +1198,7 @@ export class MaikerSDK {
       {
         authority: this.globalConfigAcc.admin,
         globalConfig: this.globalConfig,
+        strategyCreator: this.getStrategyCreatorAccount(this.globalConfigAcc.admin),
         strategy: this.strategy,
         strategyVaultX: this.strategyAcc.xVault,
         treasuryX: this.globalConfigAcc.treasury,
@@ -978,9 +1211,7 @@ export class MaikerSDK {
    * Creates instructions to get the value of a position
    */
   public async createPositionValueInstructions(params: { user: PublicKey }): Promise<TransactionInstruction[]> {
-    const positionPubkeys = this.strategyAcc.positions.filter(pubkey => !pubkey.equals(PublicKey.default));
-
-    const getPositionValueIxs = positionPubkeys.map((positionPubKey) => {
+    const getPositionValueIxs = this.positionPubkeys.map((positionPubKey) => {
       const positionData = this.positions.get(positionPubKey.toBase58());
 
       if (!positionData) {
@@ -996,6 +1227,7 @@ export class MaikerSDK {
       return maikerInstructions.getPositionValue({
         strategy: this.strategy,
         position: positionPubKey,
+        strategyPosition: deriveStrategyPosition(this.strategy, positionPubKey),
         lbPair: positionData.lbPair,
         binArrayLower,
         binArrayUpper,
@@ -1069,7 +1301,7 @@ export class MaikerSDK {
         filters: [
           {
             memcmp: {
-              offset: 8 + 32, // Skip discriminator
+              offset: 8 + 1 + 32, // Skip discriminator, version and user
               bytes: this.strategy.toBase58(), // Filter by strategy
             },
           },
@@ -1177,7 +1409,7 @@ export class MaikerSDK {
     }
 
     // Get position value from fetched positions
-    const positionPubkeys = this.strategyAcc.positions.filter(pubkey => !pubkey.equals(PublicKey.default));
+    const positionPubkeys = this.positionPubkeys;
 
     // Calculate positions value
     const positions = Array.from(this.positions.values());
@@ -1280,9 +1512,148 @@ export class MaikerSDK {
     return Math.floor(shares * currentShareValue);
   }
 
+  /**
+   * Gets the creator registry account required when the strategy creator operates the strategy
+   */
+  private getStrategyCreatorAccount(authority: PublicKey): PublicKey {
+    const isCreator = authority.equals(this.strategyAcc.creator) && !authority.equals(this.globalConfigAcc.admin);
+    return isCreator ? deriveStrategyCreator(this.strategyAcc.creator) : maikerProgramId;
+  }
+
   /**
    * Gets token balance for an account
    */
+  private async getAddLiquidityAccounts(
+    authority: PublicKey,
+    position: PublicKey,
+    lbPair: PublicKey,
+    lbPairAcc: dlmm.lbPair,
+    lowerBinId: number,
+    upperBinId: number,
+  ): Promise<{
+    accounts: maikerInstructions.AddLiquidityAccounts,
+    preInstructions: TransactionInstruction[]
+  }> {
+    // Check bin arrays
+    const preInstructions: TransactionInstruction[] = [];
+
+    const lowerBinArrayIndex = binIdToBinArrayIndex(new BN(lowerBinId));
+    const upperBinArrayIndex = BN.max(
+      lowerBinArrayIndex.add(new BN(1)),
+      binIdToBinArrayIndex(new BN(upperBinId))
+    );
+
+    // Get or create bin arrays
+    const { instructions, lowerBinArray, upperBinArray } = await getOrCreateBinArraysInstructions(
+      this.connection,
+      lbPair,
+      new BN(lowerBinArrayIndex),
+      new BN(upperBinArrayIndex),
+      authority
+    );
+
+    if (instructions.length > 0) {
+      preInstructions.push(...instructions);
+    }
+
+    // Check if extension is required
+    const useExtension =
+      isOverflowDefaultBinArrayBitmap(lowerBinArrayIndex) ||
+      isOverflowDefaultBinArrayBitmap(upperBinArrayIndex);
+
+    const binArrayBitmapExtension = useExtension
+      ? deriveBinArrayBitmapExtension(lbPair, dlmmProgramId)[0]
+      : null;
+
+    // Check if the token order is reversed
+    const isReversed = !this.xMint.address.equals(lbPairAcc.tokenXMint);
+
+    return {
+      accounts: {
+        position,
+        strategyPosition: deriveStrategyPosition(this.strategy, position),
+        authority,
+        globalConfig: this.globalConfig,
+        strategyCreator: this.getStrategyCreatorAccount(authority),
+        strategy: this.strategy,
+        lbPair,
+        tokenXMint: lbPairAcc.tokenXMint,
+        tokenYMint: lbPairAcc.tokenYMint,
+        strategyVaultX: isReversed ? this.strategyAcc.yVault : this.strategyAcc.xVault,
+        strategyVaultY: isReversed ? this.strategyAcc.xVault : this.strategyAcc.yVault,
+        reserveX: lbPairAcc.reserveX,
+        reserveY: lbPairAcc.reserveY,
+        binArrayLower: lowerBinArray,
+        binArrayUpper: upperBinArray,
+        binArrayBitmapExtension: binArrayBitmapExtension || dlmmProgramId,
+        lbClmmProgram: dlmmProgramId,
+        eventAuthority: DLMM_EVENT_AUTHORITY_PDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      },
+      preInstructions
+    };
+  }
+
+  private getRemoveLiquidityAccounts(
+    authority: PublicKey,
+    position: PublicKey,
+  ): maikerInstructions.RemoveLiquidityAccounts {
+    const positionInfo = this.positions.get(position.toBase58());
+
+    if (!positionInfo) {
+      throw new Error("Position not found");
+    }
+
+    const lbPairAcc = this.lbPairs.get(positionInfo.lbPair.toBase58());
+
+    if (!lbPairAcc) {
+      throw new Error("LB Pair not found");
+    }
+
+    const lowerBinArrayIndex = binIdToBinArrayIndex(new BN(positionInfo?.positionData?.lowerBinId ?? 0));
+    const upperBinArrayIndex = BN.max(
+      lowerBinArrayIndex.add(new BN(1)),
+      binIdToBinArrayIndex(new BN(positionInfo?.positionData?.upperBinId ?? 0))
+    );
+
+    const [lowerBinArrayPubKey] = deriveBinArray(
+      positionInfo.lbPair,
+      lowerBinArrayIndex,
+      dlmmProgramId
+    );
+    const [upperBinArrayPubKey] = deriveBinArray(
+      positionInfo.lbPair,
+      upperBinArrayIndex,
+      dlmmProgramId
+    );
+
+    const isReversed = !this.xMint.address.equals(lbPairAcc.tokenXMint);
+
+    return {
+      authority,
+      globalConfig: this.globalConfig,
+      strategyCreator: this.getStrategyCreatorAccount(authority),
+      strategy: this.strategy,
+      strategyVaultX: isReversed ? this.strategyAcc.yVault : this.strategyAcc.xVault,
+      strategyVaultY: isReversed ? this.strategyAcc.xVault : this.strategyAcc.yVault,
+      position,
+      strategyPosition: deriveStrategyPosition(this.strategy, position),
+      lbPair: positionInfo.lbPair,
+      binArrayBitmapExtension: maikerProgramId, // For testing we know no binArraybitmap extension is required
+      reserveX: lbPairAcc.reserveX,
+      reserveY: lbPairAcc.reserveY,
+      tokenXMint: lbPairAcc.tokenXMint,
+      tokenYMint: lbPairAcc.tokenYMint,
+      binArrayLower: lowerBinArrayPubKey,
+      binArrayUpper: upperBinArrayPubKey,
+      lbClmmProgram: new PublicKey(LBCLMM_PROGRAM_IDS["mainnet-beta"]),
+      eventAuthority: DLMM_EVENT_AUTHORITY_PDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      memoProgram: maikerProgramId, // Only required by remove_liquidity_by_range2
+    }
+  }
+
   private async getTokenBalance(pubkey: PublicKey): Promise<number> {
     const accInfo = await this.connection.getAccountInfo(pubkey);
     const tokenAcc = AccountLayout.decode(accInfo?.data || Buffer.from([]));
@@ -2,6 +2,7 @@ import { PublicKey } from '@solana/web3.js';
 import { BN } from '@coral-xyz/anchor';
 import { BinAndAmount } from '@meteora-ag/dlmm';
 import * as dlmm from './generated-dlmm/accounts';
+import * as maikerTypes from './generated-maiker/types';
 
 /**
  * Strategy context containing key addresses and data
@@ -22,6 +23,7 @@ export interface StrategySetupParams {
   creator: PublicKey;
   xMint: PublicKey;
   yMint: PublicKey;
+  strategyId?: number; // Distinguishes strategies of a creator on the same pair, defaults to 0
 }
 
 /**
@@ -53,6 +55,49 @@ export interface PositionLiquidityParams {
   lbPairAcc?: dlmm.lbPair;
 }
 
+/**
+ * Parameters for adding liquidity shaped by a DLMM strategy (spot, curve, bid-ask) to a position
+ */
+export interface PositionStrategyLiquidityParams {
+  authority: PublicKey;
+  position: PublicKey;
+  totalXAmount: BN;
+  totalYAmount: BN;
+  minBinId: number;
+  maxBinId: number;
+  strategyType: maikerTypes.StrategyTypeKind;
+  lbPair: PublicKey;
+  lbPairAcc?: dlmm.lbPair;
+}
+
+/**
+ * Parameters for adding liquidity from one side of the pair, distributed by weight
+ */
+export interface PositionOneSideLiquidityParams {
+  authority: PublicKey;
+  position: PublicKey;
+  side: maikerTypes.LiquiditySideKind;
+  amount: BN;
+  binLiquidityDist: maikerTypes.BinLiquidityDistributionByWeightFields[];
+  lbPair: PublicKey;
+  lbPairAcc?: dlmm.lbPair;
+}
+
+/**
+ * Parameters for adding liquidity shaped by a DLMM strategy from one side of the pair
+ */
+export interface PositionStrategyOneSideLiquidityParams {
+  authority: PublicKey;
+  position: PublicKey;
+  side: maikerTypes.LiquiditySideKind;
+  amount: BN;
+  minBinId: number;
+  maxBinId: number;
+  strategyType: maikerTypes.StrategyTypeKind;
+  lbPair: PublicKey;
+  lbPairAcc?: dlmm.lbPair;
+}
+
 /**
  * Value of a strategy position
  */
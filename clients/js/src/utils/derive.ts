@@ -1,5 +1,6 @@
 import { PublicKey } from '@solana/web3.js';
 import { PROGRAM_ID as maikerProgramId } from '../generated-maiker/programId';
+import { PROGRAM_ID as dlmmProgramId } from '../generated-dlmm/programId';
 
 /**
  * PDA constants for seeding
@@ -9,6 +10,11 @@ export const PDA_SEEDS = {
     STRATEGY_CONFIG: "strategy-config",
     USER_POSITION: "user-position",
     PENDING_WITHDRAWAL: "pending-withdrawal",
+    STRATEGY_METADATA: "strategy-metadata",
+    STRATEGY_CREATOR: "strategy-creator",
+    STRATEGY_REGISTRY: "strategy-registry",
+    STRATEGY_POSITION: "strategy-position",
+    DLMM_POSITION: "position",
 };
 
 /**
@@ -23,16 +29,45 @@ export function deriveGlobalConfig(): PublicKey {
 }
 
 /**
- * Derives the strategy config PDA for a creator
+ * Derives the strategy config PDA for a creator, mint pair and strategy id
  */
-export function deriveStrategy(creator: PublicKey, xMint: PublicKey, yMint: PublicKey): PublicKey {
+export function deriveStrategy(creator: PublicKey, xMint: PublicKey, yMint: PublicKey, strategyId: number = 0): PublicKey {
+    const strategyIdBuffer = Buffer.alloc(2);
+    strategyIdBuffer.writeUInt16LE(strategyId);
+
     const [strategy] = PublicKey.findProgramAddressSync(
-        [Buffer.from(PDA_SEEDS.STRATEGY_CONFIG), creator.toBuffer(), xMint.toBuffer(), yMint.toBuffer()],
+        [Buffer.from(PDA_SEEDS.STRATEGY_CONFIG), creator.toBuffer(), xMint.toBuffer(), yMint.toBuffer(), strategyIdBuffer],
         maikerProgramId
     );
     return strategy;
 }
 
+/**
+ * Derives the registry PDA of an approved strategy creator
+ */
+export function deriveStrategyCreator(creator: PublicKey): PublicKey {
+    const [strategyCreator] = PublicKey.findProgramAddressSync(
+        [Buffer.from(PDA_SEEDS.STRATEGY_CREATOR), creator.toBuffer()],
+        maikerProgramId
+    );
+    return strategyCreator;
+}
+
+/**
+ * Derives the registry entry PDA holding the strategy at a global strategy index.
+ * Strategies are enumerated by deriving the entries of indices 0 to the global config strategy count.
+ */
+export function deriveStrategyRegistryEntry(strategyIndex: number): PublicKey {
+    const strategyIndexBuffer = Buffer.alloc(8);
+    strategyIndexBuffer.writeBigUInt64LE(BigInt(strategyIndex));
+
+    const [strategyRegistryEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from(PDA_SEEDS.STRATEGY_REGISTRY), strategyIndexBuffer],
+        maikerProgramId
+    );
+    return strategyRegistryEntry;
+}
+
 /**
  * Derives a user position PDA
  */
@@ -59,4 +94,42 @@ export function derivePendingWithdrawal(
         maikerProgramId
     );
     return pendingWithdrawal;
+}
+
+/**
+ * Derives the metadata PDA of a strategy
+ */
+export function deriveStrategyMetadata(strategy: PublicKey): PublicKey {
+    const [strategyMetadata] = PublicKey.findProgramAddressSync(
+        [Buffer.from(PDA_SEEDS.STRATEGY_METADATA), strategy.toBuffer()],
+        maikerProgramId
+    );
+    return strategyMetadata;
+}
+
+/**
+ * Derives the PDA tracking a DLMM position of a strategy
+ */
+export function deriveStrategyPosition(strategy: PublicKey, position: PublicKey): PublicKey {
+    const [strategyPosition] = PublicKey.findProgramAddressSync(
+        [Buffer.from(PDA_SEEDS.STRATEGY_POSITION), strategy.toBuffer(), position.toBuffer()],
+        maikerProgramId
+    );
+    return strategyPosition;
+}
+
+/**
+ * Derives the DLMM position PDA of a strategy, the strategy is the base of the derivation
+ */
+export function deriveDlmmPosition(strategy: PublicKey, lbPair: PublicKey, lowerBinId: number, width: number): PublicKey {
+    const lowerBinIdBytes = Buffer.alloc(4);
+    lowerBinIdBytes.writeInt32LE(lowerBinId);
+    const widthBytes = Buffer.alloc(4);
+    widthBytes.writeInt32LE(width);
+
+    const [position] = PublicKey.findProgramAddressSync(
+        [Buffer.from(PDA_SEEDS.DLMM_POSITION), lbPair.toBuffer(), strategy.toBuffer(), lowerBinIdBytes, widthBytes],
+        dlmmProgramId
+    );
+    return position;
 }
\ No newline at end of file
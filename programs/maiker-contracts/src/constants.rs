@@ -9,8 +9,29 @@ pub const MAX_BIN_PER_ARRAY: usize = 70;
 #[constant]
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 
+/// Seed prefix of DLMM position PDAs, derived from the lb_pair, base, lower bin id and width
 #[constant]
-pub const MAX_POSITIONS: usize = 10;
+pub const DLMM_POSITION_SEED: &[u8] = b"position";
 
+/// Fixed-point scale of the share value, a share value of SHARE_PRECISION is 1 token per share
 #[constant]
-pub const SHARE_PRECISION: u64 = 1_000_000;
+pub const SHARE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Shares locked in the strategy on the first deposit, so the share value can not be inflated by donations
+#[constant]
+pub const DEAD_SHARES: u64 = 1_000;
+
+#[constant]
+pub const MAX_TRANSFER_FEE_MINTS: usize = 8;
+
+#[constant]
+pub const MAX_STRATEGY_NAME_LEN: usize = 32;
+
+#[constant]
+pub const MAX_STRATEGY_SYMBOL_LEN: usize = 10;
+
+#[constant]
+pub const MAX_STRATEGY_URI_LEN: usize = 200;
+
+#[constant]
+pub const MAX_TARGET_PAIRS: usize = 8;
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+// Domain separation prevents an inner node from being presented as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf of a depositor allowlist tree
+pub fn get_depositor_leaf(user: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, user.as_ref()]).to_bytes()
+}
+
+/// Verify a Merkle proof of sorted pairs for `leaf` against `root`
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed_root = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[NODE_PREFIX, &node, sibling]).to_bytes()
+        } else {
+            hashv(&[NODE_PREFIX, sibling, &node]).to_bytes()
+        }
+    });
+
+    computed_root == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[NODE_PREFIX, &a, &b]).to_bytes()
+        } else {
+            hashv(&[NODE_PREFIX, &b, &a]).to_bytes()
+        }
+    }
+
+    #[test]
+    fn verifies_proofs_of_allowed_users() {
+        let users: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = users.iter().map(get_depositor_leaf).collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[2], left], &root, leaves[3]));
+    }
+
+    #[test]
+    fn rejects_invalid_proofs() {
+        let users: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = users.iter().map(get_depositor_leaf).collect();
+        let root = hash_pair(leaves[0], leaves[1]);
+
+        let outsider = get_depositor_leaf(&Pubkey::new_unique());
+        assert!(!verify_merkle_proof(&[leaves[1]], &root, outsider));
+        assert!(!verify_merkle_proof(&[], &root, leaves[0]));
+        assert!(!verify_merkle_proof(&[leaves[0]], &root, leaves[0]));
+    }
+}
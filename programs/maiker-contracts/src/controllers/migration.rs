@@ -0,0 +1,363 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Allocate, Assign, Transfer},
+    Discriminator,
+};
+use bytemuck::from_bytes;
+use dlmm_interface::PositionV2;
+
+use crate::{validate, LegacyPosition, MaikerError, StrategyPosition};
+
+/// Decodes an account written with the `Legacy` layout of the `Current` account.
+/// Legacy layouts share the discriminator of the current account and are told apart by their size.
+pub fn decode_legacy_account<Legacy, Current>(data: &[u8]) -> Result<Legacy>
+where
+    Legacy: AnchorDeserialize + Space,
+    Current: Discriminator,
+{
+    validate!(
+        data.len() >= 8 && data[..8] == Current::DISCRIMINATOR,
+        MaikerError::InvalidAccountVersion,
+        "Account discriminator does not match"
+    )?;
+    validate!(
+        data.len() == 8 + Legacy::INIT_SPACE,
+        MaikerError::InvalidAccountVersion,
+        "Account of {} bytes is not a legacy layout of {} bytes",
+        data.len(),
+        8 + Legacy::INIT_SPACE
+    )?;
+
+    Ok(Legacy::deserialize(&mut &data[8..])?)
+}
+
+/// Decodes an account written with the `Legacy` layout and upgrades it to the `Current` layout.
+pub fn upgrade_legacy_account<Legacy, Current>(data: &[u8]) -> Result<Current>
+where
+    Legacy: AnchorDeserialize + Space,
+    Current: Discriminator + From<Legacy>,
+{
+    let legacy = decode_legacy_account::<Legacy, Current>(data)?;
+
+    Ok(Current::from(legacy))
+}
+
+/// Upgrades an account in place: reallocs it to the current size, tops up its rent from the payer
+/// and writes the current layout.
+pub fn migrate_account<'info, Legacy, Current>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<Current>
+where
+    Legacy: AnchorDeserialize + Space,
+    Current: AccountSerialize + Discriminator + Space + From<Legacy>,
+{
+    let current = upgrade_legacy_account::<Legacy, Current>(&account.try_borrow_data()?)?;
+    write_migrated_account(account, payer, system_program, &current)?;
+
+    Ok(current)
+}
+
+/// Reallocs an account to the current size, tops up its rent from the payer and writes the current layout
+pub fn write_migrated_account<'info, Current>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    current: &Current,
+) -> Result<()>
+where
+    Current: AccountSerialize + Space,
+{
+    let new_len = 8 + Current::INIT_SPACE;
+    top_up_rent(account, payer, system_program, new_len)?;
+
+    account.realloc(new_len, true)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    current.try_serialize(&mut writer)?;
+
+    Ok(())
+}
+
+/// Creates the StrategyPosition PDA of a position tracked inline by a legacy strategy
+pub fn create_legacy_strategy_position<'info>(
+    strategy: Pubkey,
+    legacy_position: &LegacyPosition,
+    position_info: &AccountInfo<'info>,
+    strategy_position_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    validate!(
+        position_info.key() == legacy_position.position
+            && position_info.owner == &dlmm_interface::ID,
+        MaikerError::InvalidPosition,
+        "Position {} is not the tracked position {}",
+        position_info.key(),
+        legacy_position.position
+    )?;
+    let (lb_pair, lower_bin_id, upper_bin_id) = {
+        let position_data = &position_info.try_borrow_data()?[8..];
+        let position = from_bytes::<PositionV2>(position_data);
+        (
+            position.lb_pair,
+            position.lower_bin_id,
+            position.upper_bin_id,
+        )
+    };
+
+    let (strategy_position_key, bump) = Pubkey::find_program_address(
+        &[
+            StrategyPosition::SEED_PREFIX.as_bytes(),
+            strategy.as_ref(),
+            legacy_position.position.as_ref(),
+        ],
+        &crate::ID,
+    );
+    validate!(
+        strategy_position_info.key() == strategy_position_key,
+        MaikerError::InvalidRemainingAccounts,
+        "Strategy position {} is not the PDA of position {}",
+        strategy_position_info.key(),
+        legacy_position.position
+    )?;
+
+    let space = 8 + StrategyPosition::INIT_SPACE;
+    let bump_slice = [bump];
+    let signer_seeds: &[&[u8]] = &[
+        StrategyPosition::SEED_PREFIX.as_bytes(),
+        strategy.as_ref(),
+        legacy_position.position.as_ref(),
+        &bump_slice,
+    ];
+
+    // Funded accounts can not be created, they are allocated and assigned instead
+    top_up_rent(strategy_position_info, payer, system_program, space)?;
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: strategy_position_info.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: strategy_position_info.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    let strategy_position =
+        legacy_position.to_strategy_position(strategy, lb_pair, lower_bin_id, upper_bin_id, bump);
+    let mut data = strategy_position_info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    strategy_position.try_serialize(&mut writer)?;
+
+    Ok(())
+}
+
+/// Tops up the rent of an account from the payer for the given size
+fn top_up_rent<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+) -> Result<()> {
+    let rent_top_up = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(account.lamports());
+    if rent_top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        DepositAccessMode, GlobalConfig, GlobalConfigV0, PendingWithdrawal, PendingWithdrawalV0,
+        PriceSource, StrategyConfig, StrategyConfigV0, UserPosition, UserPositionV0,
+        MAX_TRANSFER_FEE_MINTS, SHARE_PRECISION,
+    };
+
+    const GLOBAL_CONFIG_V0: &[u8] = include_bytes!("../../tests/fixtures/global_config_v0.bin");
+    const STRATEGY_CONFIG_V0: &[u8] = include_bytes!("../../tests/fixtures/strategy_config_v0.bin");
+    const USER_POSITION_V0: &[u8] = include_bytes!("../../tests/fixtures/user_position_v0.bin");
+    const PENDING_WITHDRAWAL_V0: &[u8] =
+        include_bytes!("../../tests/fixtures/pending_withdrawal_v0.bin");
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    /// Writes the upgraded account the way migrate_account does and loads it back
+    fn write_and_load<T: AccountSerialize + AccountDeserialize + Space>(account: &T) -> T {
+        let mut data = vec![0u8; 8 + T::INIT_SPACE];
+        let mut writer: &mut [u8] = &mut data[..];
+        account.try_serialize(&mut writer).unwrap();
+
+        T::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn migrates_global_config_fixture() {
+        let global_config =
+            upgrade_legacy_account::<GlobalConfigV0, GlobalConfig>(GLOBAL_CONFIG_V0).unwrap();
+        let global_config = write_and_load(&global_config);
+
+        assert_eq!(GLOBAL_CONFIG_V0.len(), 85);
+        assert_eq!(global_config.version, GlobalConfig::VERSION);
+        assert_eq!(global_config.admin, key(1));
+        assert_eq!(global_config.performance_fee_bps, 1_000);
+        assert_eq!(global_config.withdrawal_fee_bps, 50);
+        assert_eq!(global_config.treasury, key(2));
+        assert_eq!(global_config.withdrawal_interval_seconds, 3_600);
+        assert_eq!(
+            global_config.transfer_fee_mints,
+            [Pubkey::default(); MAX_TRANSFER_FEE_MINTS]
+        );
+        assert_eq!(global_config.strategy_count, 0);
+        assert_eq!(global_config.bump, 254);
+        assert_eq!(global_config.reserved, [0; 128]);
+    }
+
+    #[test]
+    fn migrates_strategy_config_fixture() {
+        let legacy =
+            decode_legacy_account::<StrategyConfigV0, StrategyConfig>(STRATEGY_CONFIG_V0).unwrap();
+        let positions = legacy.tracked_positions();
+        assert_eq!(
+            positions,
+            vec![
+                LegacyPosition {
+                    position: key(14),
+                    value: 30,
+                    value_slot: 77,
+                },
+                LegacyPosition {
+                    position: key(15),
+                    value: 12,
+                    value_slot: 80,
+                },
+            ]
+        );
+
+        let strategy = write_and_load(&StrategyConfig::from(legacy));
+
+        assert_eq!(STRATEGY_CONFIG_V0.len(), 763);
+        assert_eq!(strategy.version, StrategyConfig::VERSION);
+        assert_eq!(strategy.creator, key(6));
+        assert_eq!(strategy.x_mint, key(7));
+        assert_eq!(strategy.y_mint, key(8));
+        assert_eq!(strategy.x_vault, key(9));
+        assert_eq!(strategy.y_vault, key(10));
+        assert_eq!(strategy.strategy_shares, 1_000_000);
+        assert_eq!(strategy.fee_shares, 500);
+        assert_eq!(strategy.position_count, 2);
+        assert_eq!(strategy.valuation_round, 1);
+        assert_eq!(strategy.total_value, 42);
+        assert_eq!(strategy.value_slot, 77);
+        assert_eq!(strategy.last_rebalance_time, 1_700_000_000);
+        assert!(!strategy.is_swapping);
+        assert_eq!(strategy.price_source, PriceSource::DlmmActiveBin);
+        assert_eq!(strategy.deposit_access_mode, DepositAccessMode::Open);
+        assert_eq!(strategy.pending_withdrawal_count, 0);
+        assert_eq!(strategy.bump, 251);
+        assert!(strategy.legacy_seeds);
+        assert_eq!(strategy.high_water_mark, 0);
+        assert_eq!(strategy.reserved, [0; 103]);
+
+        // The positions move to StrategyPosition PDAs with their last value
+        let strategy_position = positions[1].to_strategy_position(key(16), key(17), -10, 20, 250);
+        let strategy_position = write_and_load(&strategy_position);
+        assert_eq!(strategy_position.version, StrategyPosition::VERSION);
+        assert_eq!(strategy_position.strategy, key(16));
+        assert_eq!(strategy_position.position, key(15));
+        assert_eq!(strategy_position.lb_pair, key(17));
+        assert_eq!(
+            (
+                strategy_position.lower_bin_id,
+                strategy_position.upper_bin_id
+            ),
+            (-10, 20)
+        );
+        assert_eq!(strategy_position.value, 12);
+        assert_eq!(strategy_position.value_slot, 80);
+        assert_eq!(strategy_position.bump, 250);
+    }
+
+    #[test]
+    fn migrates_user_position_fixture() {
+        let user_position =
+            upgrade_legacy_account::<UserPositionV0, UserPosition>(USER_POSITION_V0).unwrap();
+        let user_position = write_and_load(&user_position);
+
+        assert_eq!(USER_POSITION_V0.len(), 97);
+        assert_eq!(user_position.version, UserPosition::VERSION);
+        assert_eq!(user_position.user, key(4));
+        assert_eq!(user_position.strategy, key(5));
+        assert_eq!(user_position.strategy_share, 123_456);
+        // A legacy share value of 2_000_000 is 2.0 at the legacy precision
+        assert_eq!(user_position.last_share_value, 2 * SHARE_PRECISION);
+        assert_eq!(user_position.last_update_slot, 99);
+        assert_eq!(user_position.bump, 253);
+    }
+
+    #[test]
+    fn migrates_pending_withdrawal_fixture() {
+        let pending_withdrawal =
+            upgrade_legacy_account::<PendingWithdrawalV0, PendingWithdrawal>(PENDING_WITHDRAWAL_V0)
+                .unwrap();
+        let pending_withdrawal = write_and_load(&pending_withdrawal);
+
+        assert_eq!(pending_withdrawal.version, PendingWithdrawal::VERSION);
+        assert_eq!(pending_withdrawal.user, key(4));
+        assert_eq!(pending_withdrawal.strategy, key(5));
+        assert_eq!(pending_withdrawal.shares_amount, 1_000);
+        assert_eq!(pending_withdrawal.token_amount, 2_000);
+        assert_eq!(pending_withdrawal.initiation_timestamp, 1_700_000_000);
+        assert_eq!(pending_withdrawal.available_timestamp, 1_700_003_600);
+        assert_eq!(pending_withdrawal.bump, 252);
+    }
+
+    #[test]
+    fn rejects_accounts_without_a_legacy_layout() {
+        // Already migrated
+        let user_position =
+            upgrade_legacy_account::<UserPositionV0, UserPosition>(USER_POSITION_V0).unwrap();
+        let mut data = vec![0u8; 8 + UserPosition::INIT_SPACE];
+        let mut writer: &mut [u8] = &mut data[..];
+        user_position.try_serialize(&mut writer).unwrap();
+        assert_eq!(
+            upgrade_legacy_account::<UserPositionV0, UserPosition>(&data).err(),
+            Some(MaikerError::InvalidAccountVersion.into())
+        );
+
+        // Same size as the legacy layout but another account type
+        let data = &PENDING_WITHDRAWAL_V0[..USER_POSITION_V0.len()];
+        assert_eq!(
+            upgrade_legacy_account::<UserPositionV0, UserPosition>(data).err(),
+            Some(MaikerError::InvalidAccountVersion.into())
+        );
+    }
+}
@@ -1,2 +1,14 @@
+pub mod merkle;
+pub mod migration;
+pub mod oracle;
+pub mod price;
+pub mod pyth;
 pub mod token;
+pub mod valuation;
+pub use merkle::*;
+pub use migration::*;
+pub use oracle::*;
+pub use price::*;
+pub use pyth::*;
 pub use token::*;
+pub use valuation::*;
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use bytemuck::pod_read_unaligned;
+use dlmm_interface::{LbPair, Observation, Oracle, ORACLE_ACCOUNT_DISCM};
+
+use crate::{validate, MaikerError};
+
+const ORACLE_HEADER_OFFSET: usize = 8;
+const OBSERVATIONS_OFFSET: usize = ORACLE_HEADER_OFFSET + std::mem::size_of::<Oracle>();
+const OBSERVATION_SIZE: usize = std::mem::size_of::<Observation>();
+
+/// Calculate the time weighted average active bin id of an lb_pair over `window_seconds`.
+/// Fails if the oracle holds less history than the window.
+pub fn get_twap_active_id(
+    oracle_info: &AccountInfo,
+    lb_pair_info: &AccountInfo,
+    lb_pair: &LbPair,
+    current_timestamp: i64,
+    window_seconds: u64,
+) -> Result<i32> {
+    validate!(
+        oracle_info.owner == &dlmm_interface::ID && oracle_info.key() == lb_pair.oracle,
+        MaikerError::InvalidOracle,
+        "Oracle does not belong to lb pair {}",
+        lb_pair_info.key()
+    )?;
+
+    let data = oracle_info.try_borrow_data()?;
+    validate!(
+        data.len() >= OBSERVATIONS_OFFSET && data[..ORACLE_HEADER_OFFSET] == ORACLE_ACCOUNT_DISCM,
+        MaikerError::InvalidOracle,
+        "Invalid oracle account data"
+    )?;
+
+    let oracle = pod_read_unaligned::<Oracle>(&data[ORACLE_HEADER_OFFSET..OBSERVATIONS_OFFSET]);
+    validate!(
+        oracle.active_size > 0 && oracle.idx < oracle.length && oracle.active_size <= oracle.length,
+        MaikerError::InvalidOracle,
+        "Oracle has no observations"
+    )?;
+
+    let get_observation = |index: u64| -> Result<Observation> {
+        let start = OBSERVATIONS_OFFSET + index as usize * OBSERVATION_SIZE;
+        let bytes = data
+            .get(start..start + OBSERVATION_SIZE)
+            .ok_or(MaikerError::InvalidOracle)?;
+        Ok(pod_read_unaligned::<Observation>(bytes))
+    };
+
+    // Extrapolate the latest observation to now. The DLMM program writes observations before a
+    // swap moves the active bin, so a swap within this timestamp does not contribute.
+    let latest = get_observation(oracle.idx)?;
+    let elapsed_since_latest = current_timestamp
+        .checked_sub(latest.last_updated_at)
+        .ok_or(MaikerError::ArithmeticOverflow)?
+        .max(0);
+    let current_cumulative = latest
+        .cumulative_active_bin_id
+        .checked_add(
+            (lb_pair.active_id as i128)
+                .checked_mul(elapsed_since_latest as i128)
+                .ok_or(MaikerError::ArithmeticOverflow)?,
+        )
+        .ok_or(MaikerError::ArithmeticOverflow)?;
+
+    // Find the newest observation that is at least `window_seconds` old. An oracle with less history than
+    // the window can be dominated by a few recent observations, so it is rejected rather than used.
+    let target_timestamp = current_timestamp.saturating_sub(window_seconds as i64);
+    let mut reference: Option<Observation> = None;
+    for index in 0..oracle.active_size {
+        let observation = get_observation(index)?;
+
+        let is_newer_reference = match reference {
+            Some(r) => observation.last_updated_at > r.last_updated_at,
+            None => true,
+        };
+        if observation.last_updated_at <= target_timestamp && is_newer_reference {
+            reference = Some(observation);
+        }
+    }
+    let reference = reference.ok_or_else(|| {
+        msg!(
+            "Oracle of lb pair {} holds less than {} seconds of history",
+            lb_pair_info.key(),
+            window_seconds
+        );
+        MaikerError::InsufficientOracleHistory
+    })?;
+
+    let elapsed = current_timestamp
+        .checked_sub(reference.last_updated_at)
+        .ok_or(MaikerError::ArithmeticOverflow)?;
+    validate!(
+        elapsed > 0,
+        MaikerError::InvalidOracle,
+        "Oracle has no history to compute a TWAP"
+    )?;
+
+    let twap = current_cumulative
+        .checked_sub(reference.cumulative_active_bin_id)
+        .ok_or(MaikerError::ArithmeticOverflow)?
+        .checked_div(elapsed as i128)
+        .ok_or(MaikerError::ArithmeticOverflow)?;
+
+    i32::try_from(twap).map_err(|_| MaikerError::ArithmeticOverflow.into())
+}
+
+/// Validates that the spot active bin does not deviate from the TWAP by more than `max_deviation_bps`
+pub fn validate_twap_deviation(
+    active_id: i32,
+    twap_active_id: i32,
+    bin_step: u16,
+    max_deviation_bps: u16,
+) -> Result<()> {
+    // Every bin moves the price by `bin_step` basis points
+    let deviation_bps = (active_id as i64)
+        .checked_sub(twap_active_id as i64)
+        .ok_or(MaikerError::ArithmeticOverflow)?
+        .unsigned_abs()
+        .checked_mul(bin_step as u64)
+        .ok_or(MaikerError::ArithmeticOverflow)?;
+
+    validate!(
+        deviation_bps <= max_deviation_bps as u64,
+        MaikerError::PriceDeviationTooHigh,
+        "Active bin {} deviates {} bps from TWAP bin {}",
+        active_id,
+        deviation_bps,
+        twap_active_id
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::{bytes_of, Zeroable};
+
+    /// Builds the bytes of a DLMM oracle account holding `observations`, the last one being the latest
+    fn oracle_account_fixture(observations: &[Observation]) -> Vec<u8> {
+        let oracle = Oracle {
+            idx: observations.len() as u64 - 1,
+            active_size: observations.len() as u64,
+            length: observations.len() as u64,
+        };
+        let mut data = ORACLE_ACCOUNT_DISCM.to_vec();
+        data.extend_from_slice(bytes_of(&oracle));
+        for observation in observations {
+            data.extend_from_slice(bytes_of(observation));
+        }
+        data
+    }
+
+    fn observation(cumulative_active_bin_id: i128, last_updated_at: i64) -> Observation {
+        Observation {
+            cumulative_active_bin_id,
+            created_at: last_updated_at,
+            last_updated_at,
+        }
+    }
+
+    fn twap_active_id(data: &mut [u8], active_id: i32, now: i64, window: u64) -> Result<i32> {
+        let oracle_key = Pubkey::new_unique();
+        let lb_pair_key = Pubkey::new_unique();
+        let owner = dlmm_interface::ID;
+        let mut oracle_lamports = 0;
+        let mut lb_pair_lamports = 0;
+        let mut lb_pair_data = [];
+        let oracle_info = AccountInfo::new(
+            &oracle_key,
+            false,
+            false,
+            &mut oracle_lamports,
+            data,
+            &owner,
+            false,
+            0,
+        );
+        let lb_pair_info = AccountInfo::new(
+            &lb_pair_key,
+            false,
+            false,
+            &mut lb_pair_lamports,
+            &mut lb_pair_data,
+            &owner,
+            false,
+            0,
+        );
+        let lb_pair = LbPair {
+            active_id,
+            oracle: oracle_key,
+            ..LbPair::zeroed()
+        };
+
+        get_twap_active_id(&oracle_info, &lb_pair_info, &lb_pair, now, window)
+    }
+
+    #[test]
+    fn twap_averages_the_active_bin_over_the_window() {
+        // Bin 100 from t=1000 to t=1600, then bin 160 until now
+        let mut data =
+            oracle_account_fixture(&[observation(0, 1_000), observation(100 * 600, 1_600)]);
+
+        // The reference is the newest observation at least a window old
+        assert_eq!(twap_active_id(&mut data, 160, 1_800, 200).unwrap(), 160);
+        assert_eq!(twap_active_id(&mut data, 160, 1_800, 600).unwrap(), 115);
+        assert_eq!(twap_active_id(&mut data, 160, 1_800, 800).unwrap(), 115);
+    }
+
+    #[test]
+    fn rejects_oracle_with_less_history_than_the_window() {
+        let mut data =
+            oracle_account_fixture(&[observation(0, 1_000), observation(100 * 600, 1_600)]);
+
+        assert_eq!(
+            twap_active_id(&mut data, 160, 1_800, 801).err(),
+            Some(MaikerError::InsufficientOracleHistory.into())
+        );
+    }
+}
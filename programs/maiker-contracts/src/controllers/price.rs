@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use bytemuck::from_bytes;
+use dlmm_interface::LbPair;
+
+use crate::{
+    controllers::{
+        oracle::{get_twap_active_id, validate_twap_deviation},
+        pyth::get_pyth_price,
+    },
+    math::{
+        price_math::get_price_from_id,
+        u128x128_math::{shl_div, Rounding},
+        u64x64_math::{ONE, SCALE_OFFSET},
+    },
+    validate, MaikerError, PriceSource, StrategyConfig,
+};
+
+/// Validates that positions in the lb_pair can be valued in the strategy's x_mint.
+/// The lb_pair must trade the strategy pair, in either orientation: the strategy vaults and swaps only hold
+/// x_mint and y_mint, so tokens of any other pair could neither be counted nor swapped back.
+pub fn validate_lb_pair_mints(
+    strategy: &StrategyConfig,
+    lb_pair_key: Pubkey,
+    lb_pair: &LbPair,
+) -> Result<()> {
+    let is_strategy_pair = (lb_pair.token_x_mint == strategy.x_mint
+        && lb_pair.token_y_mint == strategy.y_mint)
+        || (lb_pair.token_x_mint == strategy.y_mint && lb_pair.token_y_mint == strategy.x_mint);
+    validate!(
+        is_strategy_pair,
+        MaikerError::InvalidLbPair,
+        "Lb pair {} does not trade the strategy pair",
+        lb_pair_key
+    )?;
+
+    Ok(())
+}
+
+/// Validates the mints of an lb_pair account, see validate_lb_pair_mints
+pub fn validate_lb_pair_info(strategy: &StrategyConfig, lb_pair_info: &AccountInfo) -> Result<()> {
+    require!(
+        lb_pair_info.owner == &dlmm_interface::ID,
+        MaikerError::InvalidLbPair
+    );
+
+    let lb_pair_data = &lb_pair_info.try_borrow_data()?[8..];
+    let lb_pair = from_bytes::<LbPair>(lb_pair_data);
+
+    validate_lb_pair_mints(strategy, lb_pair_info.key(), lb_pair)
+}
+
+/// Get the Q64.64 price of the lb_pair's token X in token Y used to value positions, according to the
+/// strategy price source. `oracle_info` is the lb_pair's DLMM oracle, or the strategy price feed for Pyth.
+pub fn get_valuation_price(
+    strategy: &StrategyConfig,
+    lb_pair_info: &AccountInfo,
+    lb_pair: &LbPair,
+    oracle_info: Option<&AccountInfo>,
+) -> Result<u128> {
+    validate_lb_pair_mints(strategy, lb_pair_info.key(), lb_pair)?;
+    let is_same_orientation =
+        lb_pair.token_x_mint == strategy.x_mint && lb_pair.token_y_mint == strategy.y_mint;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    match strategy.price_source {
+        PriceSource::DlmmActiveBin => {
+            // Reject valuation at a manipulated active bin
+            if strategy.max_twap_deviation_bps > 0 {
+                let oracle_info = oracle_info.ok_or(MaikerError::InvalidOracle)?;
+                let twap_active_id = get_twap_active_id(
+                    oracle_info,
+                    lb_pair_info,
+                    lb_pair,
+                    current_timestamp,
+                    strategy.twap_window_seconds,
+                )?;
+                validate_twap_deviation(
+                    lb_pair.active_id,
+                    twap_active_id,
+                    lb_pair.bin_step,
+                    strategy.max_twap_deviation_bps,
+                )?;
+            }
+
+            get_price_from_id(lb_pair.active_id, lb_pair.bin_step)
+        }
+        PriceSource::DlmmTwap => {
+            let oracle_info = oracle_info.ok_or(MaikerError::InvalidOracle)?;
+            let twap_active_id = get_twap_active_id(
+                oracle_info,
+                lb_pair_info,
+                lb_pair,
+                current_timestamp,
+                strategy.twap_window_seconds,
+            )?;
+
+            get_price_from_id(twap_active_id, lb_pair.bin_step)
+        }
+        PriceSource::Pyth => {
+            let price_feed_info = oracle_info.ok_or(MaikerError::InvalidPriceFeed)?;
+            validate!(
+                price_feed_info.key() == strategy.price_feed,
+                MaikerError::InvalidPriceFeed,
+                "Price feed {} is not the strategy price feed",
+                price_feed_info.key()
+            )?;
+
+            // The feed quotes the strategy's x_mint in y_mint
+            let price = get_pyth_price(
+                price_feed_info,
+                current_timestamp,
+                strategy.max_price_age_seconds,
+                strategy.max_price_confidence_bps,
+            )?
+            .to_price_per_lamport(strategy.x_decimals, strategy.y_decimals)?;
+            validate!(
+                price > 0,
+                MaikerError::InvalidPriceFeed,
+                "Price feed is zero"
+            )?;
+
+            if is_same_orientation {
+                Ok(price)
+            } else {
+                shl_div(ONE, price, SCALE_OFFSET, Rounding::Down)
+                    .ok_or(MaikerError::ArithmeticOverflow.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    fn strategy(x_mint: Pubkey, y_mint: Pubkey, price_source: PriceSource) -> StrategyConfig {
+        let mut strategy =
+            StrategyConfig::deserialize(&mut &[0u8; StrategyConfig::INIT_SPACE][..]).unwrap();
+        strategy.x_mint = x_mint;
+        strategy.y_mint = y_mint;
+        strategy.price_source = price_source;
+        strategy
+    }
+
+    fn lb_pair(token_x_mint: Pubkey, token_y_mint: Pubkey) -> LbPair {
+        LbPair {
+            token_x_mint,
+            token_y_mint,
+            ..LbPair::zeroed()
+        }
+    }
+
+    #[test]
+    fn values_the_strategy_pair_in_either_orientation() {
+        let (sol, usdc, msol) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let key = Pubkey::new_unique();
+
+        for price_source in [
+            PriceSource::DlmmActiveBin,
+            PriceSource::DlmmTwap,
+            PriceSource::Pyth,
+        ] {
+            let strategy = strategy(sol, usdc, price_source);
+            validate_lb_pair_mints(&strategy, key, &lb_pair(sol, usdc)).unwrap();
+            validate_lb_pair_mints(&strategy, key, &lb_pair(usdc, sol)).unwrap();
+
+            // Tokens of other pairs can't be held by the strategy vaults
+            for (token_x_mint, token_y_mint) in [(msol, sol), (sol, msol), (msol, usdc)] {
+                assert_eq!(
+                    validate_lb_pair_mints(&strategy, key, &lb_pair(token_x_mint, token_y_mint))
+                        .err(),
+                    Some(MaikerError::InvalidLbPair.into())
+                );
+            }
+        }
+    }
+}
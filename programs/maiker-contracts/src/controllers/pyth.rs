@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+
+use crate::{math::safe_math::SafeMath, pyth_oracle, validate, MaikerError, BASIS_POINT_MAX};
+
+// Layout of a Pyth v2 price account (`pyth-sdk-solana::state::PriceAccount`)
+const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+const PYTH_VERSION: u32 = 2;
+const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+const PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Aggregate price of a Pyth price account. The price is `price * 10^expo`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PythPrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub status: u32,
+    pub publish_time: i64,
+}
+
+impl PythPrice {
+    /// Parse a Pyth v2 price account from its raw account data
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        validate!(
+            data.len() >= PRICE_ACCOUNT_MIN_LEN,
+            MaikerError::InvalidPriceFeed,
+            "Price account data too small"
+        )?;
+        validate!(
+            read_u32(data, MAGIC_OFFSET) == PYTH_MAGIC
+                && read_u32(data, VERSION_OFFSET) == PYTH_VERSION
+                && read_u32(data, ACCOUNT_TYPE_OFFSET) == PYTH_PRICE_ACCOUNT_TYPE,
+            MaikerError::InvalidPriceFeed,
+            "Not a Pyth price account"
+        )?;
+
+        Ok(Self {
+            price: read_i64(data, AGG_PRICE_OFFSET),
+            conf: read_u64(data, AGG_CONF_OFFSET),
+            expo: read_i32(data, EXPO_OFFSET),
+            status: read_u32(data, AGG_STATUS_OFFSET),
+            publish_time: read_i64(data, TIMESTAMP_OFFSET),
+        })
+    }
+
+    /// Validates that the price is trading, fresh and has an acceptable confidence interval
+    pub fn validate(
+        &self,
+        current_timestamp: i64,
+        max_age_seconds: u64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        validate!(
+            self.status == PYTH_STATUS_TRADING && self.price > 0,
+            MaikerError::InvalidPriceFeed,
+            "Price feed is not trading"
+        )?;
+
+        let age = current_timestamp.safe_sub(self.publish_time)?;
+        validate!(
+            age >= 0 && age as u64 <= max_age_seconds,
+            MaikerError::StalePriceFeed,
+            "Price feed is {} seconds old",
+            age
+        )?;
+
+        let confidence_bps = (self.conf as u128)
+            .safe_mul(BASIS_POINT_MAX as u128)?
+            .safe_div(self.price as u128)?;
+        validate!(
+            confidence_bps <= max_confidence_bps as u128,
+            MaikerError::PriceConfidenceTooLow,
+            "Price confidence interval is {} bps",
+            confidence_bps
+        )?;
+
+        Ok(())
+    }
+
+    /// Convert to a Q64.64 price per lamport, given the decimals of the base and quote tokens
+    pub fn to_price_per_lamport(&self, base_decimals: u8, quote_decimals: u8) -> Result<u128> {
+        let scale = self
+            .expo
+            .safe_add(quote_decimals as i32)?
+            .safe_sub(base_decimals as i32)?;
+        let price = (self.price as u128).safe_shl(64)?;
+
+        let price = if scale >= 0 {
+            price.safe_mul(
+                10u128
+                    .checked_pow(scale as u32)
+                    .ok_or(MaikerError::ArithmeticOverflow)?,
+            )?
+        } else {
+            price.safe_div(
+                10u128
+                    .checked_pow(scale.unsigned_abs())
+                    .ok_or(MaikerError::ArithmeticOverflow)?,
+            )?
+        };
+
+        Ok(price)
+    }
+}
+
+/// Read and validate the price of a Pyth price account
+pub fn get_pyth_price(
+    price_feed_info: &AccountInfo,
+    current_timestamp: i64,
+    max_age_seconds: u64,
+    max_confidence_bps: u16,
+) -> Result<PythPrice> {
+    validate!(
+        price_feed_info.owner == &pyth_oracle::id(),
+        MaikerError::InvalidPriceFeed,
+        "Price feed is not owned by the Pyth program"
+    )?;
+
+    let price = PythPrice::parse(&price_feed_info.try_borrow_data()?)?;
+    price.validate(current_timestamp, max_age_seconds, max_confidence_bps)?;
+
+    Ok(price)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_i32(data: &[u8], offset: usize) -> i32 {
+    i32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_i64(data: &[u8], offset: usize) -> i64 {
+    i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::u64x64_math::ONE;
+
+    const PYTH_PRICE_ACCOUNT_LEN: usize = 3312;
+
+    /// Builds the bytes of a Pyth v2 price account
+    fn price_account_fixture(
+        price: i64,
+        conf: u64,
+        expo: i32,
+        status: u32,
+        timestamp: i64,
+    ) -> Vec<u8> {
+        let mut data = vec![0u8; PYTH_PRICE_ACCOUNT_LEN];
+        data[MAGIC_OFFSET..MAGIC_OFFSET + 4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[VERSION_OFFSET..VERSION_OFFSET + 4].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+        data[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4]
+            .copy_from_slice(&PYTH_PRICE_ACCOUNT_TYPE.to_le_bytes());
+        data[12..16].copy_from_slice(&(PYTH_PRICE_ACCOUNT_LEN as u32).to_le_bytes());
+        data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+        data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&timestamp.to_le_bytes());
+        data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&price.to_le_bytes());
+        data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&conf.to_le_bytes());
+        data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&status.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn parses_price_account() {
+        // SOL/USD at 150.12345678 +- 0.05
+        let data = price_account_fixture(15_012_345_678, 5_000_000, -8, 1, 1_700_000_000);
+
+        let price = PythPrice::parse(&data).unwrap();
+
+        assert_eq!(
+            price,
+            PythPrice {
+                price: 15_012_345_678,
+                conf: 5_000_000,
+                expo: -8,
+                status: 1,
+                publish_time: 1_700_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_non_price_accounts() {
+        let mut data = price_account_fixture(100, 1, -2, 1, 0);
+        data[MAGIC_OFFSET] = 0;
+        assert!(PythPrice::parse(&data).is_err());
+
+        let mut data = price_account_fixture(100, 1, -2, 1, 0);
+        data[ACCOUNT_TYPE_OFFSET] = 2;
+        assert!(PythPrice::parse(&data).is_err());
+
+        assert!(PythPrice::parse(&[0u8; 100]).is_err());
+    }
+
+    #[test]
+    fn validates_staleness_status_and_confidence() {
+        let now = 1_700_000_060;
+        let price = PythPrice::parse(&price_account_fixture(
+            15_000_000_000,
+            15_000_000,
+            -8,
+            1,
+            1_700_000_000,
+        ))
+        .unwrap();
+
+        // 60 seconds old, 10 bps confidence
+        assert!(price.validate(now, 60, 10).is_ok());
+        assert!(price.validate(now, 59, 10).is_err());
+        assert!(price.validate(now, 60, 9).is_err());
+
+        let halted = PythPrice { status: 0, ..price };
+        assert!(halted.validate(now, 60, 10).is_err());
+
+        let negative = PythPrice { price: -1, ..price };
+        assert!(negative.validate(now, 60, 10).is_err());
+    }
+
+    #[test]
+    fn converts_to_price_per_lamport() {
+        // 150 USDC (6 decimals) per SOL (9 decimals) is 0.15 USDC lamports per SOL lamport
+        let price = PythPrice::parse(&price_account_fixture(15_000_000_000, 0, -8, 1, 0)).unwrap();
+        let price_per_lamport = price.to_price_per_lamport(9, 6).unwrap();
+        assert_eq!(price_per_lamport, ONE * 15 / 100);
+
+        // Equal decimals keep the human price
+        let price = PythPrice::parse(&price_account_fixture(250, 0, -2, 1, 0)).unwrap();
+        assert_eq!(price.to_price_per_lamport(6, 6).unwrap(), ONE * 5 / 2);
+    }
+}
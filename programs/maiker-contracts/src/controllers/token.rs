@@ -1,12 +1,15 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_lang::{prelude::*, solana_program::program::invoke};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::{
+    instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount, TransferFeeConfig,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHook;
 use anchor_spl::token_2022::spl_token_2022::extension::{
     BaseStateWithExtensions, StateWithExtensions,
 };
-use anchor_spl::token_2022::spl_token_2022::state::Mint as MintInner;
+use anchor_spl::token_2022::spl_token_2022::state::{Account as AccountInner, Mint as MintInner};
 use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::{MaikerError, StrategyConfig};
+use crate::{validate, MaikerError, StrategyConfig};
 
 pub fn send_from_program_vault<'info>(
     token_program: &Interface<'info, TokenInterface>,
@@ -15,13 +18,15 @@ pub fn send_from_program_vault<'info>(
     strategy: &Account<'info, StrategyConfig>,
     amount: u64,
     mint: &InterfaceAccount<'info, Mint>,
+    allow_transfer_fee: bool,
 ) -> Result<()> {
     let signature_seeds = strategy.get_pda_signer();
     let signers = &[&signature_seeds[..]];
 
     let mint_account_info = mint.to_account_info();
 
-    validate_mint_fee(&mint_account_info)?;
+    validate_mint_fee(&mint_account_info, allow_transfer_fee)?;
+    validate_mint_transfer_hook(&mint_account_info)?;
 
     let cpi_accounts = TransferChecked {
         from: from.to_account_info(),
@@ -39,26 +44,33 @@ pub fn receive<'info>(
     token_program: &Interface<'info, TokenInterface>,
     from: &InterfaceAccount<'info, TokenAccount>,
     to: &InterfaceAccount<'info, TokenAccount>,
-    strategy: &Account<'info, StrategyConfig>,
+    authority: &Signer<'info>,
     amount: u64,
     mint: &InterfaceAccount<'info, Mint>,
+    allow_transfer_fee: bool,
 ) -> Result<()> {
     let mint_account_info = mint.to_account_info();
 
-    validate_mint_fee(&mint_account_info)?;
+    validate_mint_fee(&mint_account_info, allow_transfer_fee)?;
+    validate_mint_transfer_hook(&mint_account_info)?;
 
     let cpi_accounts = TransferChecked {
         from: from.to_account_info(),
         to: to.to_account_info(),
         mint: mint_account_info,
-        authority: strategy.to_account_info(),
+        authority: authority.to_account_info(),
     };
     let cpi_program = token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
     token_interface::transfer_checked(cpi_context, amount, mint.decimals)
 }
 
-pub fn validate_mint_fee(account_info: &AccountInfo) -> Result<()> {
+/// Rejects mints with a non-zero transfer fee in the current epoch, unless allowed
+pub fn validate_mint_fee(account_info: &AccountInfo, allow_transfer_fee: bool) -> Result<()> {
+    if allow_transfer_fee {
+        return Ok(());
+    }
+
     let mint_data = account_info.try_borrow_data()?;
     let mint_with_extension = StateWithExtensions::<MintInner>::unpack(&mint_data)?;
     if let Ok(fee_config) = mint_with_extension.get_extension::<TransferFeeConfig>() {
@@ -72,3 +84,159 @@ pub fn validate_mint_fee(account_info: &AccountInfo) -> Result<()> {
 
     Ok(())
 }
+
+/// Calculate the transfer fee withheld when transferring `amount` of the mint in the current epoch
+pub fn get_transfer_fee(account_info: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = account_info.try_borrow_data()?;
+    let mint_with_extension = StateWithExtensions::<MintInner>::unpack(&mint_data)?;
+    if let Ok(fee_config) = mint_with_extension.get_extension::<TransferFeeConfig>() {
+        return Ok(fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(MaikerError::ArithmeticOverflow)?);
+    }
+
+    Ok(0)
+}
+
+/// Transfer hooks require extra accounts on every transfer, which the strategy flows and DLMM CPIs do not forward
+pub fn validate_mint_transfer_hook(account_info: &AccountInfo) -> Result<()> {
+    let mint_data = account_info.try_borrow_data()?;
+    let mint_with_extension = StateWithExtensions::<MintInner>::unpack(&mint_data)?;
+    if let Ok(transfer_hook) = mint_with_extension.get_extension::<TransferHook>() {
+        let program_id: Option<Pubkey> = transfer_hook.program_id.into();
+        require!(program_id.is_none(), MaikerError::TransferHookNotSupported)
+    }
+
+    Ok(())
+}
+
+/// Transfer fees withheld in a Token-2022 account. They block closing the account until harvested.
+pub fn get_withheld_transfer_fees(account_data: &[u8]) -> Result<u64> {
+    let account_with_extension = StateWithExtensions::<AccountInner>::unpack(account_data)?;
+    if let Ok(fee_amount) = account_with_extension.get_extension::<TransferFeeAmount>() {
+        return Ok(fee_amount.withheld_amount.into());
+    }
+
+    Ok(0)
+}
+
+/// Harvest the transfer fees withheld in a strategy vault to its mint, so the vault can be closed.
+/// Harvesting is permissionless, and the fees stay claimable by the mint's withdraw authority.
+pub fn harvest_withheld_transfer_fees<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+) -> Result<()> {
+    let vault_info = vault.to_account_info();
+    let withheld_amount = get_withheld_transfer_fees(&vault_info.try_borrow_data()?)?;
+    if withheld_amount == 0 {
+        return Ok(());
+    }
+
+    msg!("Harvesting {} withheld transfer fees", withheld_amount);
+    let ix = harvest_withheld_tokens_to_mint(&token_program.key(), &mint.key(), &[&vault.key()])?;
+    invoke(
+        &ix,
+        &[
+            mint.to_account_info(),
+            vault_info,
+            token_program.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Share mints can not be closed, so a strategy recreated at the address of a closed one takes over its share mint.
+/// The mint must be empty and controlled by the strategy.
+pub fn validate_reused_share_mint(share_mint: &Mint, strategy: Pubkey) -> Result<()> {
+    validate!(
+        share_mint.mint_authority == Some(strategy).into(),
+        MaikerError::InvalidMint,
+        "Share mint is not controlled by strategy {}",
+        strategy
+    )?;
+    validate!(
+        share_mint.supply == 0,
+        MaikerError::StrategyNotEmpty,
+        "Share mint still has a supply of {}",
+        share_mint.supply
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{ExtensionType, StateWithExtensionsMut},
+        solana_program::program_pack::Pack,
+        state::AccountState,
+    };
+
+    fn share_mint(mint_authority: Pubkey, supply: u64) -> Mint {
+        let mut data = [0u8; MintInner::LEN];
+        MintInner::pack(
+            MintInner {
+                mint_authority: Some(mint_authority).into(),
+                supply,
+                decimals: 6,
+                is_initialized: true,
+                freeze_authority: None.into(),
+            },
+            &mut data,
+        )
+        .unwrap();
+        Mint::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn recreated_strategy_reuses_an_empty_share_mint() {
+        let strategy = Pubkey::new_unique();
+
+        // A closed strategy leaves its share mint empty
+        validate_reused_share_mint(&share_mint(strategy, 0), strategy).unwrap();
+
+        assert_eq!(
+            validate_reused_share_mint(&share_mint(strategy, 1), strategy).err(),
+            Some(MaikerError::StrategyNotEmpty.into())
+        );
+        assert_eq!(
+            validate_reused_share_mint(&share_mint(Pubkey::new_unique(), 0), strategy).err(),
+            Some(MaikerError::InvalidMint.into())
+        );
+    }
+
+    #[test]
+    fn reads_withheld_transfer_fees_of_a_vault() {
+        let vault = AccountInner {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+
+        // SPL Token accounts have no extensions
+        let mut data = [0u8; AccountInner::LEN];
+        AccountInner::pack(vault, &mut data).unwrap();
+        assert_eq!(get_withheld_transfer_fees(&data).unwrap(), 0);
+
+        let len = ExtensionType::try_calculate_account_len::<AccountInner>(&[
+            ExtensionType::TransferFeeAmount,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<AccountInner>::unpack_uninitialized(&mut data).unwrap();
+        state.base = vault;
+        state.pack_base();
+        state.init_account_type().unwrap();
+        state
+            .init_extension::<TransferFeeAmount>(true)
+            .unwrap()
+            .withheld_amount = 1_234.into();
+
+        assert_eq!(get_withheld_transfer_fees(&data).unwrap(), 1_234);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    controllers::price::get_valuation_price,
+    extensions::{BinArrayExtension, PositionExtension},
+    math::{
+        u128x128_math::Rounding,
+        u64x64_math::SCALE_OFFSET,
+        utils_math::{safe_mul_div_cast, safe_mul_shr_cast, safe_shl_div_cast},
+    },
+    MaikerError, StrategyConfig, StrategyPosition,
+};
+use anchor_lang::prelude::*;
+use bytemuck::from_bytes;
+use dlmm_interface::{BinArray, LbPair, PositionV2};
+
+/// Active bin of a DLMM lb_pair
+pub fn get_active_id(lb_pair_info: &AccountInfo) -> Result<i32> {
+    require!(
+        lb_pair_info.owner == &dlmm_interface::ID,
+        MaikerError::InvalidPosition
+    );
+
+    let lb_pair_data = &lb_pair_info.data.borrow()[8..];
+    let lb_pair = from_bytes::<LbPair>(lb_pair_data);
+
+    Ok(lb_pair.active_id)
+}
+
+/// Calculate the value of a DLMM position in terms of the strategy's x_mint, priced by the strategy price source.
+/// Token amounts are always split at the active bin of the lb_pair, which trades the strategy pair in either
+/// orientation.
+pub fn calculate_position_value(
+    strategy: &StrategyConfig,
+    position_info: &AccountInfo,
+    lb_pair_info: &AccountInfo,
+    bin_array_lower_info: &AccountInfo,
+    bin_array_upper_info: &AccountInfo,
+    oracle_info: Option<&AccountInfo>,
+) -> Result<u64> {
+    // All accounts must be owned by the DLMM program, otherwise the data can be forged
+    require!(
+        position_info.owner == &dlmm_interface::ID
+            && lb_pair_info.owner == &dlmm_interface::ID
+            && bin_array_lower_info.owner == &dlmm_interface::ID
+            && bin_array_upper_info.owner == &dlmm_interface::ID,
+        MaikerError::InvalidPosition
+    );
+
+    let bin_array_lower_data = &bin_array_lower_info.data.borrow()[8..];
+    let bin_array_upper_data = &bin_array_upper_info.data.borrow()[8..];
+
+    let bin_array_lower = from_bytes::<BinArray>(bin_array_lower_data);
+    let bin_array_upper = from_bytes::<BinArray>(bin_array_upper_data);
+
+    // Zero-copy access to position data - skip the 8-byte discriminator
+    let position_data = &position_info.data.borrow()[8..];
+    let position = from_bytes::<PositionV2>(position_data);
+
+    // Zero-copy access to lb_pair data - skip the 8-byte discriminator
+    let lb_pair_data = &lb_pair_info.data.borrow()[8..];
+    let lb_pair = from_bytes::<LbPair>(lb_pair_data);
+
+    // Position and bin arrays must belong to the given lb_pair
+    require!(
+        position.lb_pair == lb_pair_info.key()
+            && bin_array_lower.lb_pair == lb_pair_info.key()
+            && bin_array_upper.lb_pair == lb_pair_info.key(),
+        MaikerError::InvalidPosition
+    );
+
+    let active_bin_id = lb_pair.active_id;
+
+    // Get Price
+    let price = get_valuation_price(strategy, lb_pair_info, lb_pair, oracle_info)?;
+
+    // Initialize variables to track total token amounts
+    let mut total_token_x: u64 = 0;
+    let mut total_token_y: u64 = 0;
+
+    // Iterate through the position's bin range
+    for bin_id in position.lower_bin_id..=position.upper_bin_id {
+        // Get the liquidity share for this bin
+        let liquidity_share = position.get_liquidity_share_in_bin(bin_id)?;
+
+        // Skip if no liquidity in this bin
+        if liquidity_share == 0 {
+            continue;
+        }
+
+        // Determine which bin array contains this bin and get the bin
+        let bin = if bin_array_lower.is_bin_id_within_range(bin_id)? {
+            bin_array_lower.get_bin(bin_id)?
+        } else if bin_array_upper.is_bin_id_within_range(bin_id)? {
+            bin_array_upper.get_bin(bin_id)?
+        } else {
+            // Bin not found in either array
+            msg!("Bin not found in either array");
+            return Err(MaikerError::InvalidBinId.into());
+        };
+
+        // Calculate token amounts based on bin position relative to active bin
+        if bin_id < active_bin_id {
+            // Bins below active bin contain only token Y
+            let bin_token_y = bin.amount_y;
+            if bin_token_y > 0 && bin.liquidity_supply > 0 {
+                // Calculate proportional amount based on liquidity share
+                let position_token_y = safe_mul_div_cast(
+                    liquidity_share,
+                    bin_token_y.into(),
+                    bin.liquidity_supply,
+                    Rounding::Down,
+                )?;
+
+                total_token_y = total_token_y
+                    .checked_add(position_token_y)
+                    .ok_or(MaikerError::ArithmeticOverflow)?;
+            }
+        } else if bin_id > active_bin_id {
+            // Bins above active bin contain only token X
+            let bin_token_x = bin.amount_x;
+            if bin_token_x > 0 && bin.liquidity_supply > 0 {
+                // Calculate proportional amount based on liquidity share
+                let position_token_x = safe_mul_div_cast(
+                    liquidity_share,
+                    bin_token_x.into(),
+                    bin.liquidity_supply,
+                    Rounding::Down,
+                )?;
+
+                total_token_x = total_token_x
+                    .checked_add(position_token_x)
+                    .ok_or(MaikerError::ArithmeticOverflow)?;
+            }
+        } else {
+            // Active bin contains both tokens
+            let bin_token_x = bin.amount_x;
+            let bin_token_y = bin.amount_y;
+
+            if bin.liquidity_supply > 0 {
+                // Calculate proportional amounts based on liquidity share
+                if bin_token_x > 0 {
+                    let position_token_x = safe_mul_div_cast(
+                        liquidity_share,
+                        bin_token_x.into(),
+                        bin.liquidity_supply,
+                        Rounding::Down,
+                    )?;
+
+                    total_token_x = total_token_x
+                        .checked_add(position_token_x)
+                        .ok_or(MaikerError::ArithmeticOverflow)?;
+                }
+
+                if bin_token_y > 0 {
+                    let position_token_y = safe_mul_div_cast(
+                        liquidity_share,
+                        bin_token_y.into(),
+                        bin.liquidity_supply,
+                        Rounding::Down,
+                    )?;
+
+                    total_token_y = total_token_y
+                        .checked_add(position_token_y)
+                        .ok_or(MaikerError::ArithmeticOverflow)?;
+                }
+            }
+        }
+    }
+
+    msg!("Total token x: {}", total_token_x);
+    msg!("Total token y: {}", total_token_y);
+    msg!("Price per token y: {}", price);
+
+    let calculate_in_x = strategy.x_mint == lb_pair.token_x_mint;
+    let total_value;
+
+    if calculate_in_x {
+        // Strategy's mint_x === lb_pair's token_x_mint
+        // Calculate value in terms of token X
+        let token_y_in_x = if total_token_y > 0 {
+            // Use safe_shl_div_cast to shift right by SCALE_OFFSET and divide token_y by price
+            safe_shl_div_cast(total_token_y.into(), price, SCALE_OFFSET, Rounding::Down)?
+        } else {
+            0
+        };
+
+        msg!("Token y in x: {}", token_y_in_x);
+
+        // Total value in terms of token X
+        total_value = total_token_x
+            .checked_add(token_y_in_x)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+
+        msg!("Total value in terms of token X: {}", total_value);
+    } else {
+        // Strategy's mint_x === lb_pair's token_y_mint
+        // Calculate value in terms of token Y
+        let token_x_in_y = if total_token_x > 0 {
+            // Use safe_mul_shr_cast to multiply token_x by price and shift right by SCALE_OFFSET
+            safe_mul_shr_cast(total_token_x.into(), price, SCALE_OFFSET, Rounding::Down)?
+        } else {
+            0
+        };
+
+        msg!("Token x in y: {}", token_x_in_y);
+
+        // Total value in terms of token Y
+        total_value = total_token_y
+            .checked_add(token_x_in_y)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+
+        msg!("Total value in terms of token Y: {}", total_value);
+    }
+
+    Ok(total_value)
+}
+
+/// Record a rebalance at the current slot.
+/// Cached position values are invalidated for deposits and withdrawals in this slot.
+pub fn record_rebalance(strategy: &mut StrategyConfig) -> Result<()> {
+    let clock = Clock::get()?;
+    strategy.mark_rebalance(clock.slot, clock.unix_timestamp);
+
+    Ok(())
+}
+
+/// Revalue a position after a CPI changed its liquidity or fees.
+/// The rebalance is recorded, and the touched position is revalued so its cached value reflects the new liquidity. Oracle guarded strategies have to revalue
+/// through get_position_value instead.
+pub fn revalue_position(
+    strategy: &mut StrategyConfig,
+    strategy_position: &mut StrategyPosition,
+    position_info: &AccountInfo,
+    lb_pair_info: &AccountInfo,
+    bin_array_lower_info: &AccountInfo,
+    bin_array_upper_info: &AccountInfo,
+) -> Result<()> {
+    record_rebalance(strategy)?;
+
+    if !strategy.requires_oracle() {
+        let position_value = calculate_position_value(
+            strategy,
+            position_info,
+            lb_pair_info,
+            bin_array_lower_info,
+            bin_array_upper_info,
+            None,
+        )?;
+        strategy.update_position_value(strategy_position, position_value, Clock::get()?.slot)?;
+    }
+
+    Ok(())
+}
@@ -50,4 +50,91 @@ pub enum MaikerError {
 
     #[msg("Non-zero transfer fee")]
     NonZeroTransferFee,
+
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+
+    #[msg("Invalid strategy config")]
+    InvalidStrategyConfig,
+
+    #[msg("Invalid oracle account")]
+    InvalidOracle,
+
+    #[msg("Active bin deviates too much from the oracle TWAP")]
+    PriceDeviationTooHigh,
+
+    #[msg("Invalid price feed")]
+    InvalidPriceFeed,
+
+    #[msg("Price feed is stale")]
+    StalePriceFeed,
+
+    #[msg("Price feed confidence interval too wide")]
+    PriceConfidenceTooLow,
+
+    #[msg("Lb pair does not match the strategy tokens")]
+    InvalidLbPair,
+
+    #[msg("Mints with a transfer hook are not supported")]
+    TransferHookNotSupported,
+
+    #[msg("Max transfer fee mints reached")]
+    MaxTransferFeeMintsReached,
+
+    #[msg("Invalid mint")]
+    InvalidMint,
+
+    #[msg("Deposit amount is below the strategy minimum")]
+    DepositBelowMinimum,
+
+    #[msg("Deposit exceeds the strategy deposit cap")]
+    StrategyDepositCapExceeded,
+
+    #[msg("Deposit exceeds the per user deposit cap")]
+    UserDepositCapExceeded,
+
+    #[msg("User is not allowed to deposit into this strategy")]
+    DepositorNotAllowed,
+
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+
+    #[msg("Deposit would mint zero shares")]
+    ZeroSharesMinted,
+
+    #[msg("User position still holds shares")]
+    UserPositionNotEmpty,
+
+    #[msg("User has a pending withdrawal")]
+    PendingWithdrawalExists,
+
+    #[msg("Invalid share transfer")]
+    InvalidShareTransfer,
+
+    #[msg("Strategy is not empty")]
+    StrategyNotEmpty,
+
+    #[msg("Invalid strategy metadata")]
+    InvalidStrategyMetadata,
+
+    #[msg("Creator is not approved to create strategies")]
+    CreatorNotApproved,
+
+    #[msg("Account has no legacy layout to migrate")]
+    InvalidAccountVersion,
+
+    #[msg("Invalid liquidity reduction")]
+    InvalidLiquidityReduction,
+
+    #[msg("Liquidity add exceeds the strategy liquidity limits")]
+    LiquidityLimitExceeded,
+
+    #[msg("Oracle holds less history than the TWAP window")]
+    InsufficientOracleHistory,
+
+    #[msg("Legacy account does not belong to the given accounts")]
+    LegacyAccountMismatch,
+
+    #[msg("Memo program is required for this instruction")]
+    MemoProgramRequired,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{DepositAccessMode, PriceSource, StrategyCategory};
+
 // User Events
 #[event]
 pub struct CreateStrategyEvent {
@@ -7,6 +9,9 @@ pub struct CreateStrategyEvent {
     pub creator: Pubkey,
     pub x_mint: Pubkey,
     pub y_mint: Pubkey,
+    pub strategy_id: u16,
+    pub strategy_index: u64,
+    pub share_mint: Pubkey,
     pub timestamp: i64,
 }
 
@@ -15,7 +20,7 @@ pub struct UserDepositEvent {
     pub user: Pubkey,
     pub strategy: Pubkey,
     pub shares_amount: u64,
-    pub current_share_value: u64,
+    pub current_share_value: u128,
     pub token_amount: u64,
     pub performance_fee_shares: u64,
     pub timestamp: i64,
@@ -26,7 +31,7 @@ pub struct InitiateWithdrawEvent {
     pub user: Pubkey,
     pub strategy: Pubkey,
     pub shares_amount: u64,
-    pub current_share_value: u64,
+    pub current_share_value: u128,
     pub token_amount: u64,
     pub withdrawal_fee_shares: u64,
     pub performance_fee_shares: u64,
@@ -40,6 +45,25 @@ pub struct ProcessWithdrawEvent {
     pub strategy: Pubkey,
     pub shares_amount: u64,
     pub token_amount: u64,
+    pub transfer_fee: u64, // Token-2022 transfer fee withheld from token_amount
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CloseUserPositionEvent {
+    pub user: Pubkey,
+    pub strategy: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferSharesEvent {
+    pub strategy: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub shares_amount: u64,
+    pub current_share_value: u128,
+    pub performance_fee_shares: u64,
     pub timestamp: i64,
 }
 
@@ -54,11 +78,81 @@ pub struct UpdateGlobalConfigEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct UpdateDepositorAllowanceEvent {
+    pub strategy: Pubkey,
+    pub user: Pubkey,
+    pub allowed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateStrategyCreatorEvent {
+    pub creator: Pubkey,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateTransferFeeMintEvent {
+    pub mint: Pubkey,
+    pub allowed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateStrategyConfigEvent {
+    pub strategy: Pubkey,
+    pub twap_window_seconds: u64,
+    pub max_twap_deviation_bps: u16,
+    pub price_source: PriceSource,
+    pub price_feed: Pubkey,
+    pub max_price_age_seconds: u64,
+    pub max_price_confidence_bps: u16,
+    pub max_total_deposits: u64,
+    pub max_deposit_per_user: u64,
+    pub min_deposit_amount: u64,
+    pub deposit_access_mode: DepositAccessMode,
+    pub deposit_merkle_root: [u8; 32],
+    pub max_liquidity_bin_width: u32,
+    pub max_active_bin_distance: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateStrategyMetadataEvent {
+    pub strategy: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub category: StrategyCategory,
+    pub target_pairs: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CloseStrategyEvent {
+    pub strategy: Pubkey,
+    pub creator: Pubkey,
+    pub x_dust_amount: u64, // Dust swept to the treasury
+    pub y_dust_amount: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ClaimFeeSharesEvent {
     pub strategy: Pubkey,
     pub fee_shares: u64,
     pub token_amount: u64,
+    pub transfer_fee: u64, // Token-2022 transfer fee withheld from token_amount
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MigrateAccountEvent {
+    pub account: Pubkey,
+    pub version: u8, // Layout version the account was upgraded to
     pub timestamp: i64,
 }
 
@@ -7,3 +7,13 @@ pub mod lighthouse {
     use anchor_lang::declare_id;
     declare_id!("L2TExMFKdjpN9kozasaurPirfHy9P8sbXoAN1qA3S95");
 }
+
+pub mod memo {
+    use anchor_lang::declare_id;
+    declare_id!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+}
+
+pub mod pyth_oracle {
+    use anchor_lang::declare_id;
+    declare_id!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+}
@@ -0,0 +1,61 @@
+use crate::{error::MaikerError, state::*, UpdateDepositorAllowanceEvent, ANCHOR_DISCRIMINATOR};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct AddDepositorAllowance<'info> {
+    #[account(
+        mut,
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c)) @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + DepositorAllowance::INIT_SPACE,
+        seeds = [DepositorAllowance::SEED_PREFIX.as_bytes(), strategy.key().as_ref(), user.as_ref()],
+        bump
+    )]
+    pub depositor_allowance: Account<'info, DepositorAllowance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_depositor_allowance_handler(
+    ctx: Context<AddDepositorAllowance>,
+    user: Pubkey,
+) -> Result<()> {
+    let strategy = &ctx.accounts.strategy;
+
+    ctx.accounts.depositor_allowance.initialize(
+        strategy.key(),
+        user,
+        ctx.bumps.depositor_allowance,
+    );
+
+    // Emit event
+    emit!(UpdateDepositorAllowanceEvent {
+        strategy: strategy.key(),
+        user,
+        allowed: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
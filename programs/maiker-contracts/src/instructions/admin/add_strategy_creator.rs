@@ -0,0 +1,47 @@
+use crate::{error::MaikerError, state::*, UpdateStrategyCreatorEvent, ANCHOR_DISCRIMINATOR};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct AddStrategyCreator<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == global_config.admin @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + StrategyCreator::INIT_SPACE,
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), creator.as_ref()],
+        bump
+    )]
+    pub strategy_creator: Account<'info, StrategyCreator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_strategy_creator_handler(
+    ctx: Context<AddStrategyCreator>,
+    creator: Pubkey,
+) -> Result<()> {
+    ctx.accounts
+        .strategy_creator
+        .initialize(creator, ctx.bumps.strategy_creator);
+
+    // Emit event
+    emit!(UpdateStrategyCreatorEvent {
+        creator,
+        approved: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
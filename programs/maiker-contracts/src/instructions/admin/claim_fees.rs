@@ -1,11 +1,13 @@
-use crate::{state::*, ClaimFeeSharesEvent, MaikerError};
+use crate::{
+    controllers, math::u128x128_math::Rounding, state::*, ClaimFeeSharesEvent, MaikerError,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct ClaimFees<'info> {
     #[account(
-        constraint = authority.key() == global_config.admin @ MaikerError::NotAuthorized
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c)) @ MaikerError::NotAuthorized
     )]
     pub authority: Signer<'info>,
 
@@ -15,6 +17,13 @@ pub struct ClaimFees<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
     #[account(mut)]
     pub strategy: Box<Account<'info, StrategyConfig>>,
 
@@ -22,17 +31,25 @@ pub struct ClaimFees<'info> {
         mut,
         token::mint = strategy.x_mint,
         token::authority = strategy.key(),
+        token::token_program = token_x_program,
     )]
-    pub strategy_vault_x: Box<Account<'info, TokenAccount>>,
+    pub strategy_vault_x: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = strategy.x_mint,
         token::authority = global_config.treasury,
+        token::token_program = token_x_program,
     )]
-    pub treasury_x: Box<Account<'info, TokenAccount>>,
+    pub treasury_x: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        address = strategy.x_mint,
+        mint::token_program = token_x_program,
+    )]
+    pub x_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_x_program: Interface<'info, TokenInterface>,
 }
 
 pub fn claim_fees_handler(ctx: Context<ClaimFees>, shares_to_claim: Option<u64>) -> Result<()> {
@@ -40,8 +57,8 @@ pub fn claim_fees_handler(ctx: Context<ClaimFees>, shares_to_claim: Option<u64>)
     let clock = Clock::get()?;
     let slot = clock.slot;
 
-    // Validate that all positions have up-to-date values
-    strategy.validate_position_values_freshness(slot)?;
+    // Validate that the cached strategy value is up-to-date
+    strategy.validate_strategy_value_freshness(slot)?;
 
     // Check if there are any pending fees
     require!(strategy.fee_shares > 0, MaikerError::NoFeesToWithdraw);
@@ -60,25 +77,27 @@ pub fn claim_fees_handler(ctx: Context<ClaimFees>, shares_to_claim: Option<u64>)
         strategy.calculate_total_strategy_value(ctx.accounts.strategy_vault_x.amount)?;
     msg!("total_strategy_value: {}", total_strategy_value);
 
-    let current_share_value = strategy.calculate_share_value(total_strategy_value)?;
+    let current_share_value =
+        strategy.calculate_share_value(total_strategy_value, Rounding::Down)?;
     msg!("current_share_value: {}", current_share_value);
 
     let token_amount =
         strategy.calculate_withdrawal_amount(shares_to_claim, current_share_value)?;
     msg!("token_amount: {}", token_amount);
 
+    // Claims are never blocked by a transfer fee, the withheld amount is disclosed instead
+    let transfer_fee =
+        controllers::token::get_transfer_fee(&ctx.accounts.x_mint.to_account_info(), token_amount)?;
+
     // Transfer tokens to treasury
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.strategy_vault_x.to_account_info(),
-                to: ctx.accounts.treasury_x.to_account_info(),
-                authority: strategy.to_account_info(),
-            },
-            &[&strategy.get_pda_signer()],
-        ),
+    controllers::token::send_from_program_vault(
+        &ctx.accounts.token_x_program,
+        &ctx.accounts.strategy_vault_x,
+        &ctx.accounts.treasury_x,
+        strategy,
         token_amount,
+        &ctx.accounts.x_mint,
+        true,
     )?;
 
     // Burn shares
@@ -90,6 +109,7 @@ pub fn claim_fees_handler(ctx: Context<ClaimFees>, shares_to_claim: Option<u64>)
         strategy: strategy.key(),
         fee_shares: shares_to_claim,
         token_amount,
+        transfer_fee,
         timestamp: clock.unix_timestamp,
     });
 
@@ -0,0 +1,201 @@
+use crate::{controllers, state::*, CloseStrategyEvent, MaikerError};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CloseStrategy<'info> {
+    #[account(
+        constraint = authority.key() == global_config.admin @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: Receives the rent of the strategy accounts
+    #[account(mut, address = strategy.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(mut, close = creator)]
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    /// Registry entry of the strategy, closed along with it
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            StrategyRegistryEntry::SEED_PREFIX.as_bytes(),
+            strategy.strategy_index.to_le_bytes().as_ref()
+        ],
+        bump = strategy_registry_entry.bump,
+    )]
+    pub strategy_registry_entry: Box<Account<'info, StrategyRegistryEntry>>,
+
+    /// CHECK: The strategy metadata PDA, closed along with the strategy when it exists
+    #[account(
+        mut,
+        seeds = [StrategyMetadata::SEED_PREFIX.as_bytes(), strategy.key().as_ref()],
+        bump
+    )]
+    pub strategy_metadata: UncheckedAccount<'info>,
+
+    /// Stays open empty, since share mints can not be closed. A recreated strategy takes it over.
+    #[account(
+        address = strategy.share_mint,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        address = strategy.x_vault,
+        token::token_program = token_x_program,
+    )]
+    pub strategy_vault_x: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = strategy.y_vault,
+        token::token_program = token_y_program,
+    )]
+    pub strategy_vault_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = strategy.x_mint,
+        token::authority = global_config.treasury,
+        token::token_program = token_x_program,
+    )]
+    pub treasury_x: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = strategy.y_mint,
+        token::authority = global_config.treasury,
+        token::token_program = token_y_program,
+    )]
+    pub treasury_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives the transfer fees withheld in the vault
+    #[account(
+        mut,
+        address = strategy.x_mint,
+        mint::token_program = token_x_program,
+    )]
+    pub x_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Receives the transfer fees withheld in the vault
+    #[account(
+        mut,
+        address = strategy.y_mint,
+        mint::token_program = token_y_program,
+    )]
+    pub y_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+pub fn close_strategy_handler(ctx: Context<CloseStrategy>) -> Result<()> {
+    let strategy = &ctx.accounts.strategy;
+
+    // Only strategies without users, fees, positions and pending withdrawals can be closed
+    strategy.validate_closable()?;
+    controllers::token::validate_reused_share_mint(&ctx.accounts.share_mint, strategy.key())?;
+
+    // Sweep the dust left by rounding and the dead shares to the treasury
+    let x_dust_amount = ctx.accounts.strategy_vault_x.amount;
+    if x_dust_amount > 0 {
+        controllers::token::send_from_program_vault(
+            &ctx.accounts.token_x_program,
+            &ctx.accounts.strategy_vault_x,
+            &ctx.accounts.treasury_x,
+            strategy,
+            x_dust_amount,
+            &ctx.accounts.x_mint,
+            true,
+        )?;
+    }
+
+    let y_dust_amount = ctx.accounts.strategy_vault_y.amount;
+    if y_dust_amount > 0 {
+        controllers::token::send_from_program_vault(
+            &ctx.accounts.token_y_program,
+            &ctx.accounts.strategy_vault_y,
+            &ctx.accounts.treasury_y,
+            strategy,
+            y_dust_amount,
+            &ctx.accounts.y_mint,
+            true,
+        )?;
+    }
+
+    // Token-2022 accounts holding withheld transfer fees can not be closed
+    controllers::token::harvest_withheld_transfer_fees(
+        &ctx.accounts.token_x_program,
+        &ctx.accounts.strategy_vault_x,
+        &ctx.accounts.x_mint,
+    )?;
+    controllers::token::harvest_withheld_transfer_fees(
+        &ctx.accounts.token_y_program,
+        &ctx.accounts.strategy_vault_y,
+        &ctx.accounts.y_mint,
+    )?;
+
+    // Close both vaults, returning their rent to the creator
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_x_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.strategy_vault_x.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: strategy.to_account_info(),
+        },
+        &[&strategy.get_pda_signer()],
+    ))?;
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_y_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.strategy_vault_y.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: strategy.to_account_info(),
+        },
+        &[&strategy.get_pda_signer()],
+    ))?;
+
+    // The metadata must not outlive the strategy, so it is closed whenever it was created
+    let strategy_metadata_info = ctx.accounts.strategy_metadata.to_account_info();
+    if !strategy_metadata_info.data_is_empty() {
+        require!(
+            strategy_metadata_info.owner == &crate::ID,
+            MaikerError::InvalidStrategyMetadata
+        );
+
+        let creator_info = ctx.accounts.creator.to_account_info();
+        let creator_lamports = creator_info
+            .lamports()
+            .checked_add(strategy_metadata_info.lamports())
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        **creator_info.lamports.borrow_mut() = creator_lamports;
+        **strategy_metadata_info.lamports.borrow_mut() = 0;
+
+        strategy_metadata_info.assign(&system_program::ID);
+        strategy_metadata_info.realloc(0, false)?;
+    }
+
+    // Emit event
+    emit!(CloseStrategyEvent {
+        strategy: strategy.key(),
+        creator: strategy.creator,
+        x_dust_amount,
+        y_dust_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -13,7 +13,7 @@ use crate::{controllers, jupiter_mainnet_6, lighthouse, validate, GlobalConfig};
 pub struct FlashSwap<'info> {
     #[account(
         mut,
-        constraint = authority.key() == global_config.admin
+        constraint = authority.key() == global_config.admin @ MaikerError::NotAuthorized
     )]
     pub authority: Signer<'info>,
 
@@ -28,39 +28,47 @@ pub struct FlashSwap<'info> {
 
     #[account(mut,
         associated_token::mint = in_mint,
-        associated_token::authority = strategy
+        associated_token::authority = strategy,
+        associated_token::token_program = in_token_program
     )]
     pub in_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut,
         associated_token::mint = out_mint,
-        associated_token::authority = strategy
+        associated_token::authority = strategy,
+        associated_token::token_program = out_token_program
     )]
     pub out_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut,
         associated_token::mint = in_mint,
-        associated_token::authority = authority
+        associated_token::authority = authority,
+        associated_token::token_program = in_token_program
     )]
     pub in_admin_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut,
         associated_token::mint = out_mint,
-        associated_token::authority = authority
+        associated_token::authority = authority,
+        associated_token::token_program = out_token_program
     )]
     pub out_admin_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
+        mint::token_program = in_token_program,
         constraint = if x_to_y { in_mint.key() == strategy.x_mint } else { in_mint.key() == strategy.y_mint }
     )]
     pub in_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
+        mint::token_program = out_token_program,
         constraint = if x_to_y { out_mint.key() == strategy.y_mint } else { out_mint.key() == strategy.x_mint }
     )]
     pub out_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    pub in_token_program: Interface<'info, TokenInterface>,
+
+    pub out_token_program: Interface<'info, TokenInterface>,
 
     /// CHECK: Instructions sysvar for validation
     #[account(address = instructions::ID)]
@@ -90,12 +98,15 @@ pub fn begin_swap_handler(ctx: Context<FlashSwap>, _x_to_y: bool, amount_in: u64
 
     // 2. Transfer tokens from the appropriate vault to the admin
     controllers::token::send_from_program_vault(
-        &ctx.accounts.token_program,
+        &ctx.accounts.in_token_program,
         in_vault,
         in_admin_ata,
         strategy,
         amount_in,
         in_mint,
+        ctx.accounts
+            .global_config
+            .is_transfer_fee_mint_allowed(&in_mint.key()),
     )?;
 
     // 3. Assert
@@ -187,13 +198,25 @@ pub fn begin_swap_handler(ctx: Context<FlashSwap>, _x_to_y: bool, amount_in: u64
             )?;
 
             validate!(
-                ctx.accounts.token_program.key() == ix.accounts[8].pubkey,
+                ctx.accounts.out_mint.key() == ix.accounts[8].pubkey,
+                MaikerError::InvalidSwap,
+                "Invalid out mint"
+            )?;
+
+            validate!(
+                ctx.accounts.in_token_program.key() == ix.accounts[9].pubkey,
+                MaikerError::InvalidSwap,
+                "Invalid in token program"
+            )?;
+
+            validate!(
+                ctx.accounts.out_token_program.key() == ix.accounts[10].pubkey,
                 MaikerError::InvalidSwap,
-                "Invalid token program"
+                "Invalid out token program"
             )?;
 
             validate!(
-                ctx.accounts.instructions_sysvar.key() == ix.accounts[9].pubkey,
+                ctx.accounts.instructions_sysvar.key() == ix.accounts[11].pubkey,
                 MaikerError::InvalidSwap,
                 "Invalid instructions sysvar"
             )?;
@@ -257,6 +280,10 @@ pub fn end_swap_handler(ctx: Context<FlashSwap>, _x_to_y: bool) -> Result<()> {
     let in_mint = &ctx.accounts.in_mint;
     let out_mint = &ctx.accounts.out_mint;
 
+    let global_config = &ctx.accounts.global_config;
+    let allow_in_transfer_fee = global_config.is_transfer_fee_mint_allowed(&in_mint.key());
+    let allow_out_transfer_fee = global_config.is_transfer_fee_mint_allowed(&out_mint.key());
+
     // Check for residual tokens and transfer back to strategy vault
     let mut amount_in = strategy.swap_amount_in;
     if in_admin_ata.amount > strategy.swap_initial_in_amount_admin {
@@ -266,12 +293,13 @@ pub fn end_swap_handler(ctx: Context<FlashSwap>, _x_to_y: bool) -> Result<()> {
             .ok_or(MaikerError::ArithmeticOverflow)?;
 
         controllers::token::receive(
-            &ctx.accounts.token_program,
+            &ctx.accounts.in_token_program,
             in_admin_ata,
             in_vault,
-            strategy,
+            &ctx.accounts.authority,
             residual,
             &in_mint,
+            allow_in_transfer_fee,
         )?;
         in_admin_ata.reload()?;
         in_vault.reload()?;
@@ -284,25 +312,35 @@ pub fn end_swap_handler(ctx: Context<FlashSwap>, _x_to_y: bool) -> Result<()> {
     // Check the out amount and transfer back to strategy vault
     let mut amount_out = 0_u64;
     if out_admin_ata.amount > strategy.swap_initial_out_amount_admin {
-        amount_out = out_admin_ata
+        let gross_amount_out = out_admin_ata
             .amount
             .checked_sub(strategy.swap_initial_out_amount_admin)
             .ok_or(MaikerError::ArithmeticOverflow)?;
+        let out_vault_balance = out_vault.amount;
 
         controllers::token::receive(
-            &ctx.accounts.token_program,
+            &ctx.accounts.out_token_program,
             out_admin_ata,
             out_vault,
-            strategy,
-            amount_out,
+            &ctx.accounts.authority,
+            gross_amount_out,
             &out_mint,
+            allow_out_transfer_fee,
         )?;
         out_admin_ata.reload()?;
         out_vault.reload()?;
+
+        // The strategy is credited with the amount received by the vault, net of any transfer fee
+        amount_out = out_vault
+            .amount
+            .checked_sub(out_vault_balance)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
     }
 
     strategy.end_swap(amount_in, in_mint.key(), out_mint.key())?;
 
+    controllers::record_rebalance(strategy)?;
+
     msg!(
         "EndSwap: Completed swap. In: {} Out: {}",
         amount_in,
@@ -1,7 +1,23 @@
+pub mod add_depositor_allowance;
+pub mod add_strategy_creator;
 pub mod claim_fees;
+pub mod close_strategy;
 pub mod flash_swap;
+pub mod remove_depositor_allowance;
+pub mod remove_strategy_creator;
 pub mod update_global_config;
+pub mod update_strategy_config;
+pub mod update_strategy_metadata;
+pub mod update_transfer_fee_mint;
 
+pub use add_depositor_allowance::*;
+pub use add_strategy_creator::*;
 pub use claim_fees::*;
+pub use close_strategy::*;
 pub use flash_swap::*;
+pub use remove_depositor_allowance::*;
+pub use remove_strategy_creator::*;
 pub use update_global_config::*;
+pub use update_strategy_config::*;
+pub use update_strategy_metadata::*;
+pub use update_transfer_fee_mint::*;
@@ -0,0 +1,46 @@
+use crate::{error::MaikerError, state::*, UpdateDepositorAllowanceEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveDepositorAllowance<'info> {
+    #[account(
+        mut,
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c)) @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [DepositorAllowance::SEED_PREFIX.as_bytes(), strategy.key().as_ref(), depositor_allowance.user.as_ref()],
+        bump = depositor_allowance.bump,
+        close = authority
+    )]
+    pub depositor_allowance: Account<'info, DepositorAllowance>,
+}
+
+pub fn remove_depositor_allowance_handler(ctx: Context<RemoveDepositorAllowance>) -> Result<()> {
+    // Emit event
+    emit!(UpdateDepositorAllowanceEvent {
+        strategy: ctx.accounts.strategy.key(),
+        user: ctx.accounts.depositor_allowance.user,
+        allowed: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
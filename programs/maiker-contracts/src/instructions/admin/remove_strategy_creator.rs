@@ -0,0 +1,37 @@
+use crate::{error::MaikerError, state::*, UpdateStrategyCreatorEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveStrategyCreator<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == global_config.admin @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy_creator.creator.as_ref()],
+        bump = strategy_creator.bump,
+        close = authority
+    )]
+    pub strategy_creator: Account<'info, StrategyCreator>,
+}
+
+/// The creator can no longer create strategies or operate the ones it created, which stay operable by the admin
+pub fn remove_strategy_creator_handler(ctx: Context<RemoveStrategyCreator>) -> Result<()> {
+    // Emit event
+    emit!(UpdateStrategyCreatorEvent {
+        creator: ctx.accounts.strategy_creator.creator,
+        approved: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::{error::MaikerError, state::*, UpdateStrategyConfigEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateStrategyConfig<'info> {
+    #[account(
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c)) @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
+    #[account(mut)]
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+}
+
+pub fn update_strategy_config_handler(
+    ctx: Context<UpdateStrategyConfig>,
+    strategy_config_args: StrategyConfigArgs,
+) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+
+    strategy.update_strategy_config(strategy_config_args)?;
+
+    // Emit event
+    emit!(UpdateStrategyConfigEvent {
+        strategy: strategy.key(),
+        twap_window_seconds: strategy.twap_window_seconds,
+        max_twap_deviation_bps: strategy.max_twap_deviation_bps,
+        price_source: strategy.price_source,
+        price_feed: strategy.price_feed,
+        max_price_age_seconds: strategy.max_price_age_seconds,
+        max_price_confidence_bps: strategy.max_price_confidence_bps,
+        max_total_deposits: strategy.max_total_deposits,
+        max_deposit_per_user: strategy.max_deposit_per_user,
+        min_deposit_amount: strategy.min_deposit_amount,
+        deposit_access_mode: strategy.deposit_access_mode,
+        deposit_merkle_root: strategy.deposit_merkle_root,
+        max_liquidity_bin_width: strategy.max_liquidity_bin_width,
+        max_active_bin_distance: strategy.max_active_bin_distance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::{error::MaikerError, state::*, UpdateStrategyMetadataEvent, ANCHOR_DISCRIMINATOR};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateStrategyMetadata<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == strategy.creator
+            || authority.key() == global_config.admin @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + StrategyMetadata::INIT_SPACE,
+        seeds = [StrategyMetadata::SEED_PREFIX.as_bytes(), strategy.key().as_ref()],
+        bump
+    )]
+    pub strategy_metadata: Box<Account<'info, StrategyMetadata>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_strategy_metadata_handler(
+    ctx: Context<UpdateStrategyMetadata>,
+    strategy_metadata_args: StrategyMetadataArgs,
+) -> Result<()> {
+    let strategy_metadata = &mut ctx.accounts.strategy_metadata;
+
+    strategy_metadata.update_strategy_metadata(
+        ctx.accounts.strategy.key(),
+        strategy_metadata_args,
+        ctx.bumps.strategy_metadata,
+    )?;
+
+    // Emit event
+    emit!(UpdateStrategyMetadataEvent {
+        strategy: strategy_metadata.strategy,
+        authority: ctx.accounts.authority.key(),
+        name: strategy_metadata.name.clone(),
+        symbol: strategy_metadata.symbol.clone(),
+        uri: strategy_metadata.uri.clone(),
+        category: strategy_metadata.category,
+        target_pairs: strategy_metadata.target_pairs.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::{error::MaikerError, state::*, UpdateTransferFeeMintEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateTransferFeeMint<'info> {
+    #[account(
+        constraint = authority.key() == global_config.admin @ MaikerError::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn update_transfer_fee_mint_handler(
+    ctx: Context<UpdateTransferFeeMint>,
+    mint: Pubkey,
+    allowed: bool,
+) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    global_config.set_transfer_fee_mint(mint, allowed)?;
+
+    // Emit event
+    emit!(UpdateTransferFeeMintEvent {
+        mint,
+        allowed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
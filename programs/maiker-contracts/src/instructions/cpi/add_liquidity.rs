@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 
-use crate::{GlobalConfig, StrategyConfig};
+use crate::{
+    controllers::{price::validate_lb_pair_info, valuation::revalue_position},
+    GlobalConfig, MaikerError, StrategyConfig, StrategyCreator, StrategyPosition,
+};
 use dlmm_interface::{
     add_liquidity_by_weight_invoke_signed, AddLiquidityByWeightAccounts, AddLiquidityByWeightIxArgs,
 };
@@ -59,7 +62,7 @@ pub struct AddLiquidity<'info> {
     /// The authority of the strategy
     #[account(
         mut,
-        constraint = authority.key() == global_config.admin
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c))
     )]
     pub authority: Signer<'info>,
 
@@ -69,6 +72,13 @@ pub struct AddLiquidity<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
     #[account(mut)]
     pub strategy: Box<Account<'info, StrategyConfig>>,
 
@@ -77,8 +87,24 @@ pub struct AddLiquidity<'info> {
     #[account(mut)]
     pub position: UncheckedAccount<'info>,
 
-    /// CHECK: The LB pair account
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            StrategyPosition::SEED_PREFIX.as_bytes(),
+            strategy.key().as_ref(),
+            position.key().as_ref(),
+        ],
+        bump = strategy_position.bump,
+        has_one = lb_pair,
+        constraint = strategy_position.has_valid_derivation() @ MaikerError::InvalidPosition
+    )]
+    pub strategy_position: Box<Account<'info, StrategyPosition>>,
+
+    /// CHECK: The LB pair account, trading the strategy pair
+    #[account(
+        mut,
+        constraint = validate_lb_pair_info(&strategy, &lb_pair.to_account_info()).is_ok() @ MaikerError::InvalidLbPair
+    )]
     pub lb_pair: UncheckedAccount<'info>,
 
     /// CHECK: Token X mint
@@ -90,12 +116,22 @@ pub struct AddLiquidity<'info> {
     pub token_y_mint: UncheckedAccount<'info>,
 
     /// The strategy vault for token X
-    #[account(mut)]
-    pub strategy_vault_x: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = strategy.x_vault,
+        token::mint = token_x_mint,
+        token::token_program = token_x_program,
+    )]
+    pub strategy_vault_x: InterfaceAccount<'info, TokenAccount>,
 
     /// The strategy vault for token Y
-    #[account(mut)]
-    pub strategy_vault_y: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = strategy.y_vault,
+        token::mint = token_y_mint,
+        token::token_program = token_y_program,
+    )]
+    pub strategy_vault_y: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: The reserve account for token X
     #[account(mut)]
@@ -125,8 +161,11 @@ pub struct AddLiquidity<'info> {
     /// CHECK: Event authority for lb_clmm
     pub event_authority: UncheckedAccount<'info>,
 
-    /// The token program
-    pub token_program: Program<'info, Token>,
+    /// The token program for token X
+    pub token_x_program: Interface<'info, TokenInterface>,
+
+    /// The token program for token Y
+    pub token_y_program: Interface<'info, TokenInterface>,
 
     /// The system program
     pub system_program: Program<'info, System>,
@@ -136,17 +175,10 @@ pub fn add_liquidity_handler(
     ctx: Context<AddLiquidity>,
     liquidity_parameter: LiquidityParameterByWeight,
 ) -> Result<()> {
-    // TODO: Validation Lb Pair and Strategy token accounts
-
     let strategy_signer = ctx.accounts.strategy.get_pda_signer();
     let strategy_signer_seeds = &[&strategy_signer[..]];
 
-    let bin_array_bitmap_extension_info =
-        if let Some(account) = &ctx.accounts.bin_array_bitmap_extension {
-            account.to_account_info()
-        } else {
-            ctx.accounts.lb_clmm_program.to_account_info()
-        };
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
 
     let accounts = AddLiquidityByWeightAccounts {
         position: &ctx.accounts.position.to_account_info(),
@@ -161,8 +193,8 @@ pub fn add_liquidity_handler(
         bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
         bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
         sender: &ctx.accounts.strategy.to_account_info(),
-        token_x_program: &ctx.accounts.token_program.to_account_info(),
-        token_y_program: &ctx.accounts.token_program.to_account_info(),
+        token_x_program: &ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: &ctx.accounts.token_y_program.to_account_info(),
         event_authority: &ctx.accounts.event_authority.to_account_info(),
         program: &ctx.accounts.lb_clmm_program.to_account_info(),
     };
@@ -175,7 +207,48 @@ pub fn add_liquidity_handler(
         liquidity_parameter: dlmm_liquidity_parameter,
     };
 
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
     add_liquidity_by_weight_invoke_signed(accounts, args, strategy_signer_seeds)?;
 
-    Ok(())
+    ctx.accounts
+        .add_liquidity_to_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+impl<'info> AddLiquidity<'info> {
+    pub(crate) fn bin_array_bitmap_extension_info(&self) -> AccountInfo<'info> {
+        if let Some(account) = &self.bin_array_bitmap_extension {
+            account.to_account_info()
+        } else {
+            self.lb_clmm_program.to_account_info()
+        }
+    }
+
+    /// Raises the position cost basis by the tokens the add took from the vaults
+    pub(crate) fn add_liquidity_to_cost_basis(
+        &mut self,
+        vault_x_before: u64,
+        vault_y_before: u64,
+    ) -> Result<()> {
+        self.strategy_vault_x.reload()?;
+        self.strategy_vault_y.reload()?;
+        self.strategy_position.add_liquidity(
+            vault_x_before.saturating_sub(self.strategy_vault_x.amount),
+            vault_y_before.saturating_sub(self.strategy_vault_y.amount),
+        )
+    }
+
+    pub(crate) fn revalue_position(&mut self) -> Result<()> {
+        revalue_position(
+            &mut self.strategy,
+            &mut self.strategy_position,
+            &self.position.to_account_info(),
+            &self.lb_pair.to_account_info(),
+            &self.bin_array_lower.to_account_info(),
+            &self.bin_array_upper.to_account_info(),
+        )
+    }
 }
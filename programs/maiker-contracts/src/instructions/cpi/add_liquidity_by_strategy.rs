@@ -0,0 +1,412 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    controllers::valuation::get_active_id, validate, AddLiquidity, MaikerError, StrategyConfig,
+};
+use dlmm_interface::{
+    add_liquidity_by_strategy2_ix, add_liquidity_by_strategy_invoke_signed,
+    add_liquidity_by_strategy_one_side_invoke_signed, AddLiquidityByStrategy2Accounts,
+    AddLiquidityByStrategy2IxArgs, AddLiquidityByStrategyAccounts, AddLiquidityByStrategyIxArgs,
+    AddLiquidityByStrategyOneSideAccounts, AddLiquidityByStrategyOneSideIxArgs,
+    RemainingAccountsInfo, ADD_LIQUIDITY_BY_STRATEGY2_IX_ACCOUNTS_LEN,
+};
+
+// Copy from lb_clmm
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum StrategyType {
+    SpotOneSide,
+    CurveOneSide,
+    BidAskOneSide,
+    SpotBalanced,
+    CurveBalanced,
+    BidAskBalanced,
+    SpotImBalanced,
+    CurveImBalanced,
+    BidAskImBalanced,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
+pub struct StrategyParameters {
+    /// Lower bin of the liquidity shape
+    pub min_bin_id: i32,
+    /// Upper bin of the liquidity shape
+    pub max_bin_id: i32,
+    /// Shape of the liquidity distribution
+    pub strategy_type: StrategyType,
+    /// Parameters of the shape, unused by the current strategy types
+    pub parameteres: [u8; 64],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
+pub struct LiquidityParameterByStrategy {
+    /// Amount of X token to deposit
+    pub amount_x: u64,
+    /// Amount of Y token to deposit
+    pub amount_y: u64,
+    /// Active bin that integrator observe off-chain
+    pub active_id: i32,
+    /// max active bin slippage allowed
+    pub max_active_bin_slippage: i32,
+    /// Shape of the liquidity
+    pub strategy_parameters: StrategyParameters,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
+pub struct LiquidityParameterByStrategyOneSide {
+    /// Amount of token to deposit
+    pub amount: u64,
+    /// Active bin that integrator observe off-chain
+    pub active_id: i32,
+    /// max active bin slippage allowed
+    pub max_active_bin_slippage: i32,
+    /// Shape of the liquidity
+    pub strategy_parameters: StrategyParameters,
+}
+
+/// Token a one sided liquidity add takes from the strategy vaults
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum LiquiditySide {
+    /// Token X, deposited at or above the active bin
+    X,
+    /// Token Y, deposited at or below the active bin
+    Y,
+}
+
+impl LiquiditySide {
+    /// Validates that the bins can hold only this side of the pair
+    pub fn validate_bins(self, min_bin_id: i32, max_bin_id: i32, active_id: i32) -> Result<()> {
+        let valid = match self {
+            LiquiditySide::X => min_bin_id >= active_id,
+            LiquiditySide::Y => max_bin_id <= active_id,
+        };
+        validate!(
+            valid,
+            MaikerError::InvalidBinId,
+            "Bins {} to {} do not hold only token {:?} at the active bin {}",
+            min_bin_id,
+            max_bin_id,
+            self,
+            active_id
+        )?;
+
+        Ok(())
+    }
+}
+
+impl StrategyParameters {
+    /// Validates the bins of the liquidity shape against the strategy liquidity limits
+    pub fn validate_bins(&self, strategy: &StrategyConfig, active_id: i32) -> Result<()> {
+        strategy.validate_liquidity_bins(self.min_bin_id, self.max_bin_id, active_id)
+    }
+}
+
+impl From<StrategyType> for dlmm_interface::StrategyType {
+    fn from(strategy_type: StrategyType) -> Self {
+        match strategy_type {
+            StrategyType::SpotOneSide => Self::SpotOneSide,
+            StrategyType::CurveOneSide => Self::CurveOneSide,
+            StrategyType::BidAskOneSide => Self::BidAskOneSide,
+            StrategyType::SpotBalanced => Self::SpotBalanced,
+            StrategyType::CurveBalanced => Self::CurveBalanced,
+            StrategyType::BidAskBalanced => Self::BidAskBalanced,
+            StrategyType::SpotImBalanced => Self::SpotImBalanced,
+            StrategyType::CurveImBalanced => Self::CurveImBalanced,
+            StrategyType::BidAskImBalanced => Self::BidAskImBalanced,
+        }
+    }
+}
+
+impl From<StrategyParameters> for dlmm_interface::StrategyParameters {
+    fn from(param: StrategyParameters) -> Self {
+        Self {
+            min_bin_id: param.min_bin_id,
+            max_bin_id: param.max_bin_id,
+            strategy_type: param.strategy_type.into(),
+            parameteres: param.parameteres,
+        }
+    }
+}
+
+impl From<LiquidityParameterByStrategy> for dlmm_interface::LiquidityParameterByStrategy {
+    fn from(param: LiquidityParameterByStrategy) -> Self {
+        Self {
+            amount_x: param.amount_x,
+            amount_y: param.amount_y,
+            active_id: param.active_id,
+            max_active_bin_slippage: param.max_active_bin_slippage,
+            strategy_parameters: param.strategy_parameters.into(),
+        }
+    }
+}
+
+impl From<LiquidityParameterByStrategyOneSide>
+    for dlmm_interface::LiquidityParameterByStrategyOneSide
+{
+    fn from(param: LiquidityParameterByStrategyOneSide) -> Self {
+        Self {
+            amount: param.amount,
+            active_id: param.active_id,
+            max_active_bin_slippage: param.max_active_bin_slippage,
+            strategy_parameters: param.strategy_parameters.into(),
+        }
+    }
+}
+
+pub fn add_liquidity_by_strategy_handler(
+    ctx: Context<AddLiquidity>,
+    liquidity_parameter: LiquidityParameterByStrategy,
+) -> Result<()> {
+    let active_id = get_active_id(&ctx.accounts.lb_pair.to_account_info())?;
+    liquidity_parameter
+        .strategy_parameters
+        .validate_bins(&ctx.accounts.strategy, active_id)?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+
+    let accounts = AddLiquidityByStrategyAccounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token_x: &ctx.accounts.strategy_vault_x.to_account_info(),
+        user_token_y: &ctx.accounts.strategy_vault_y.to_account_info(),
+        reserve_x: &ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: &ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: &ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: &ctx.accounts.token_y_mint.to_account_info(),
+        bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_x_program: &ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: &ctx.accounts.token_y_program.to_account_info(),
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    let args = AddLiquidityByStrategyIxArgs {
+        liquidity_parameter: liquidity_parameter.into(),
+    };
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    add_liquidity_by_strategy_invoke_signed(accounts, args, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .add_liquidity_to_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+// Adds liquidity shaped by a DLMM strategy from both vaults, supporting Token-2022 pairs
+pub fn add_liquidity_by_strategy2_handler(
+    ctx: Context<AddLiquidity>,
+    liquidity_parameter: LiquidityParameterByStrategy,
+) -> Result<()> {
+    let active_id = get_active_id(&ctx.accounts.lb_pair.to_account_info())?;
+    liquidity_parameter
+        .strategy_parameters
+        .validate_bins(&ctx.accounts.strategy, active_id)?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+
+    let accounts = AddLiquidityByStrategy2Accounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token_x: &ctx.accounts.strategy_vault_x.to_account_info(),
+        user_token_y: &ctx.accounts.strategy_vault_y.to_account_info(),
+        reserve_x: &ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: &ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: &ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: &ctx.accounts.token_y_mint.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_x_program: &ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: &ctx.accounts.token_y_program.to_account_info(),
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    // Transfer hook mints are rejected, so the remaining accounts only hold the bin arrays of the position
+    let args = AddLiquidityByStrategy2IxArgs {
+        liquidity_parameter: liquidity_parameter.into(),
+        remaining_accounts_info: RemainingAccountsInfo { slices: vec![] },
+    };
+
+    let mut ix = add_liquidity_by_strategy2_ix(accounts.into(), args)?;
+    let account_infos: [AccountInfo; ADD_LIQUIDITY_BY_STRATEGY2_IX_ACCOUNTS_LEN] = accounts.into();
+    let mut account_infos = account_infos.to_vec();
+
+    let bin_array_lower_info = ctx.accounts.bin_array_lower.to_account_info();
+    let bin_array_upper_info = ctx.accounts.bin_array_upper.to_account_info();
+    ix.accounts
+        .push(AccountMeta::new(bin_array_lower_info.key(), false));
+    account_infos.push(bin_array_lower_info.clone());
+    if bin_array_upper_info.key() != bin_array_lower_info.key() {
+        ix.accounts
+            .push(AccountMeta::new(bin_array_upper_info.key(), false));
+        account_infos.push(bin_array_upper_info);
+    }
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    invoke_signed(&ix, &account_infos, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .add_liquidity_to_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+pub fn add_liquidity_by_strategy_one_side_handler(
+    ctx: Context<AddLiquidity>,
+    side: LiquiditySide,
+    liquidity_parameter: LiquidityParameterByStrategyOneSide,
+) -> Result<()> {
+    let strategy_parameters = &liquidity_parameter.strategy_parameters;
+    ctx.accounts.validate_one_side_bins(
+        side,
+        strategy_parameters.min_bin_id,
+        strategy_parameters.max_bin_id,
+    )?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+
+    let (user_token, reserve, token_mint, token_program) =
+        ctx.accounts.one_side_token_accounts(side);
+
+    let accounts = AddLiquidityByStrategyOneSideAccounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token: &user_token,
+        reserve: &reserve,
+        token_mint: &token_mint,
+        bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_program: &token_program,
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    let args = AddLiquidityByStrategyOneSideIxArgs {
+        liquidity_parameter: liquidity_parameter.into(),
+    };
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    add_liquidity_by_strategy_one_side_invoke_signed(accounts, args, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .add_liquidity_to_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+impl<'info> AddLiquidity<'info> {
+    /// Validates the bins of a one sided add against the active bin and the strategy liquidity limits
+    pub(crate) fn validate_one_side_bins(
+        &self,
+        side: LiquiditySide,
+        min_bin_id: i32,
+        max_bin_id: i32,
+    ) -> Result<()> {
+        let active_id = get_active_id(&self.lb_pair.to_account_info())?;
+        side.validate_bins(min_bin_id, max_bin_id, active_id)?;
+        self.strategy
+            .validate_liquidity_bins(min_bin_id, max_bin_id, active_id)
+    }
+
+    /// Vault, reserve, mint and token program of one side of the pair
+    pub(crate) fn one_side_token_accounts(
+        &self,
+        side: LiquiditySide,
+    ) -> (
+        AccountInfo<'info>,
+        AccountInfo<'info>,
+        AccountInfo<'info>,
+        AccountInfo<'info>,
+    ) {
+        match side {
+            LiquiditySide::X => (
+                self.strategy_vault_x.to_account_info(),
+                self.reserve_x.to_account_info(),
+                self.token_x_mint.to_account_info(),
+                self.token_x_program.to_account_info(),
+            ),
+            LiquiditySide::Y => (
+                self.strategy_vault_y.to_account_info(),
+                self.reserve_y.to_account_info(),
+                self.token_y_mint.to_account_info(),
+                self.token_y_program.to_account_info(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_sided_bins_must_match_the_side() {
+        LiquiditySide::X.validate_bins(10, 20, 10).unwrap();
+        LiquiditySide::Y.validate_bins(-20, 10, 10).unwrap();
+
+        assert_eq!(
+            LiquiditySide::X.validate_bins(9, 20, 10).err(),
+            Some(MaikerError::InvalidBinId.into())
+        );
+        assert_eq!(
+            LiquiditySide::Y.validate_bins(-20, 11, 10).err(),
+            Some(MaikerError::InvalidBinId.into())
+        );
+    }
+
+    #[test]
+    fn strategy_shapes_respect_the_liquidity_limits() {
+        let mut strategy =
+            StrategyConfig::deserialize(&mut &[0u8; StrategyConfig::INIT_SPACE][..]).unwrap();
+        strategy.max_liquidity_bin_width = 70;
+        strategy.max_active_bin_distance = 40;
+
+        let strategy_parameters = |min_bin_id, max_bin_id| StrategyParameters {
+            min_bin_id,
+            max_bin_id,
+            strategy_type: StrategyType::SpotBalanced,
+            parameteres: [0; 64],
+        };
+
+        strategy_parameters(-35, 34)
+            .validate_bins(&strategy, 0)
+            .unwrap();
+        strategy_parameters(60, 100)
+            .validate_bins(&strategy, 70)
+            .unwrap();
+
+        // Too wide or too far from the active bin
+        for (min_bin_id, max_bin_id, active_id) in [(-35, 35, 0), (10, 41, 0), (60, 100, 50)] {
+            assert_eq!(
+                strategy_parameters(min_bin_id, max_bin_id)
+                    .validate_bins(&strategy, active_id)
+                    .err(),
+                Some(MaikerError::LiquidityLimitExceeded.into())
+            );
+        }
+        assert_eq!(
+            strategy_parameters(5, 4).validate_bins(&strategy, 0).err(),
+            Some(MaikerError::InvalidBinId.into())
+        );
+    }
+}
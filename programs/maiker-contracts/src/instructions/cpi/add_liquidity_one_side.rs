@@ -0,0 +1,285 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{AddLiquidity, BinLiquidityDistributionByWeight, LiquiditySide, MaikerError};
+use dlmm_interface::{
+    add_liquidity_one_side_invoke_signed, add_liquidity_one_side_precise2_ix,
+    add_liquidity_one_side_precise_invoke_signed, AddLiquidityOneSideAccounts,
+    AddLiquidityOneSideIxArgs, AddLiquidityOneSidePrecise2Accounts,
+    AddLiquidityOneSidePrecise2IxArgs, AddLiquidityOneSidePreciseAccounts,
+    AddLiquidityOneSidePreciseIxArgs, RemainingAccountsInfo,
+    ADD_LIQUIDITY_ONE_SIDE_PRECISE2_IX_ACCOUNTS_LEN,
+};
+
+// Copy from lb_clmm
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
+pub struct LiquidityOneSideParameter {
+    /// Amount of token to deposit
+    pub amount: u64,
+    /// Active bin that integrator observe off-chain
+    pub active_id: i32,
+    /// max active bin slippage allowed
+    pub max_active_bin_slippage: i32,
+    /// Liquidity distribution to each bins
+    pub bin_liquidity_dist: Vec<BinLiquidityDistributionByWeight>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug, Default)]
+pub struct CompressedBinDepositAmount {
+    /// Bin ID to deposit to
+    pub bin_id: i32,
+    /// Amount to deposit, divided by the decompress multiplier
+    pub amount: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
+pub struct AddLiquiditySingleSidePreciseParameter {
+    /// Exact amount deposited to each bin
+    pub bins: Vec<CompressedBinDepositAmount>,
+    /// Multiplier applied to the compressed bin amounts
+    pub decompress_multiplier: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
+pub struct AddLiquiditySingleSidePreciseParameter2 {
+    /// Exact amount deposited to each bin
+    pub bins: Vec<CompressedBinDepositAmount>,
+    /// Multiplier applied to the compressed bin amounts
+    pub decompress_multiplier: u64,
+    /// Max total amount taken from the vault
+    pub max_amount: u64,
+}
+
+impl From<LiquidityOneSideParameter> for dlmm_interface::LiquidityOneSideParameter {
+    fn from(param: LiquidityOneSideParameter) -> Self {
+        Self {
+            amount: param.amount,
+            active_id: param.active_id,
+            max_active_bin_slippage: param.max_active_bin_slippage,
+            bin_liquidity_dist: param
+                .bin_liquidity_dist
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<CompressedBinDepositAmount> for dlmm_interface::CompressedBinDepositAmount {
+    fn from(bin: CompressedBinDepositAmount) -> Self {
+        Self {
+            bin_id: bin.bin_id,
+            amount: bin.amount,
+        }
+    }
+}
+
+impl From<AddLiquiditySingleSidePreciseParameter>
+    for dlmm_interface::AddLiquiditySingleSidePreciseParameter
+{
+    fn from(param: AddLiquiditySingleSidePreciseParameter) -> Self {
+        Self {
+            bins: param.bins.into_iter().map(Into::into).collect(),
+            decompress_multiplier: param.decompress_multiplier,
+        }
+    }
+}
+
+impl From<AddLiquiditySingleSidePreciseParameter2>
+    for dlmm_interface::AddLiquiditySingleSidePreciseParameter2
+{
+    fn from(param: AddLiquiditySingleSidePreciseParameter2) -> Self {
+        Self {
+            bins: param.bins.into_iter().map(Into::into).collect(),
+            decompress_multiplier: param.decompress_multiplier,
+            max_amount: param.max_amount,
+        }
+    }
+}
+
+/// Lowest and highest bin a liquidity add deposits to
+pub fn bin_range(bin_ids: impl Iterator<Item = i32>) -> Result<(i32, i32)> {
+    let range = bin_ids.fold(None, |range: Option<(i32, i32)>, bin_id| match range {
+        Some((min_bin_id, max_bin_id)) => Some((min_bin_id.min(bin_id), max_bin_id.max(bin_id))),
+        None => Some((bin_id, bin_id)),
+    });
+
+    range.ok_or_else(|| MaikerError::InvalidBinId.into())
+}
+
+// Adds liquidity from the vault of one side, distributed by weight
+pub fn add_liquidity_one_side_handler(
+    ctx: Context<AddLiquidity>,
+    side: LiquiditySide,
+    liquidity_parameter: LiquidityOneSideParameter,
+) -> Result<()> {
+    let (min_bin_id, max_bin_id) = bin_range(
+        liquidity_parameter
+            .bin_liquidity_dist
+            .iter()
+            .map(|dist| dist.bin_id),
+    )?;
+    ctx.accounts
+        .validate_one_side_bins(side, min_bin_id, max_bin_id)?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+    let (user_token, reserve, token_mint, token_program) =
+        ctx.accounts.one_side_token_accounts(side);
+
+    let accounts = AddLiquidityOneSideAccounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token: &user_token,
+        reserve: &reserve,
+        token_mint: &token_mint,
+        bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_program: &token_program,
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    let args = AddLiquidityOneSideIxArgs {
+        liquidity_parameter: liquidity_parameter.into(),
+    };
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    add_liquidity_one_side_invoke_signed(accounts, args, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .add_liquidity_to_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+// Adds exact amounts per bin from the vault of one side
+pub fn add_liquidity_one_side_precise_handler(
+    ctx: Context<AddLiquidity>,
+    side: LiquiditySide,
+    parameter: AddLiquiditySingleSidePreciseParameter,
+) -> Result<()> {
+    let (min_bin_id, max_bin_id) = bin_range(parameter.bins.iter().map(|bin| bin.bin_id))?;
+    ctx.accounts
+        .validate_one_side_bins(side, min_bin_id, max_bin_id)?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+    let (user_token, reserve, token_mint, token_program) =
+        ctx.accounts.one_side_token_accounts(side);
+
+    let accounts = AddLiquidityOneSidePreciseAccounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token: &user_token,
+        reserve: &reserve,
+        token_mint: &token_mint,
+        bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_program: &token_program,
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    let args = AddLiquidityOneSidePreciseIxArgs {
+        parameter: parameter.into(),
+    };
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    add_liquidity_one_side_precise_invoke_signed(accounts, args, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .add_liquidity_to_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+// Adds exact amounts per bin from the vault of one side, capped by a max amount
+pub fn add_liquidity_one_side_precise2_handler(
+    ctx: Context<AddLiquidity>,
+    side: LiquiditySide,
+    liquidity_parameter: AddLiquiditySingleSidePreciseParameter2,
+) -> Result<()> {
+    let (min_bin_id, max_bin_id) =
+        bin_range(liquidity_parameter.bins.iter().map(|bin| bin.bin_id))?;
+    ctx.accounts
+        .validate_one_side_bins(side, min_bin_id, max_bin_id)?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+    let (user_token, reserve, token_mint, token_program) =
+        ctx.accounts.one_side_token_accounts(side);
+
+    let accounts = AddLiquidityOneSidePrecise2Accounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token: &user_token,
+        reserve: &reserve,
+        token_mint: &token_mint,
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_program: &token_program,
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    // Transfer hook mints are rejected, so the remaining accounts only hold the bin arrays of the position
+    let args = AddLiquidityOneSidePrecise2IxArgs {
+        liquidity_parameter: liquidity_parameter.into(),
+        remaining_accounts_info: RemainingAccountsInfo { slices: vec![] },
+    };
+
+    let mut ix = add_liquidity_one_side_precise2_ix(accounts.into(), args)?;
+    let account_infos: [AccountInfo; ADD_LIQUIDITY_ONE_SIDE_PRECISE2_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    let mut account_infos = account_infos.to_vec();
+
+    let bin_array_lower_info = ctx.accounts.bin_array_lower.to_account_info();
+    let bin_array_upper_info = ctx.accounts.bin_array_upper.to_account_info();
+    ix.accounts
+        .push(AccountMeta::new(bin_array_lower_info.key(), false));
+    account_infos.push(bin_array_lower_info.clone());
+    if bin_array_upper_info.key() != bin_array_lower_info.key() {
+        ix.accounts
+            .push(AccountMeta::new(bin_array_upper_info.key(), false));
+        account_infos.push(bin_array_upper_info);
+    }
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    invoke_signed(&ix, &account_infos, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .add_liquidity_to_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bin_range_spans_unordered_bins() {
+        assert_eq!(bin_range([3, -2, 7, 0].into_iter()).unwrap(), (-2, 7));
+        assert_eq!(bin_range([5].into_iter()).unwrap(), (5, 5));
+        assert_eq!(
+            bin_range(std::iter::empty()).err(),
+            Some(MaikerError::InvalidBinId.into())
+        );
+    }
+}
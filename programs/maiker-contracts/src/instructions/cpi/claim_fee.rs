@@ -1,12 +1,16 @@
-use crate::state::*;
-use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
-use dlmm_interface::{claim_fee_invoke_signed, ClaimFeeAccounts};
+use crate::{controllers::valuation::revalue_position, memo, state::*, MaikerError};
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use bytemuck::from_bytes;
+use dlmm_interface::{
+    claim_fee2_ix, ClaimFee2Accounts, ClaimFee2IxArgs, PositionV2, RemainingAccountsInfo,
+    CLAIM_FEE2_IX_ACCOUNTS_LEN,
+};
 
 #[derive(Accounts)]
 pub struct ClaimFee<'info> {
     #[account(
-        constraint = authority.key() == global_config.admin
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c))
     )]
     pub authority: Signer<'info>,
 
@@ -16,6 +20,13 @@ pub struct ClaimFee<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
     #[account(mut)]
     pub strategy: Box<Account<'info, StrategyConfig>>,
 
@@ -24,19 +35,32 @@ pub struct ClaimFee<'info> {
         token::mint = token_x_mint,
         token::authority = strategy
     )]
-    pub strategy_vault_x: Box<Account<'info, TokenAccount>>,
+    pub strategy_vault_x: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = token_y_mint,
         token::authority = strategy
     )]
-    pub strategy_vault_y: Box<Account<'info, TokenAccount>>,
+    pub strategy_vault_y: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// CHECK: This is the position account
     #[account(mut)]
     pub position: UncheckedAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [
+            StrategyPosition::SEED_PREFIX.as_bytes(),
+            strategy.key().as_ref(),
+            position.key().as_ref(),
+        ],
+        bump = strategy_position.bump,
+        has_one = lb_pair,
+        constraint = strategy_position.has_valid_derivation() @ MaikerError::InvalidPosition
+    )]
+    pub strategy_position: Box<Account<'info, StrategyPosition>>,
+
     /// CHECK: This is the LB pair account
     #[account(mut)]
     pub lb_pair: UncheckedAccount<'info>,
@@ -71,20 +95,33 @@ pub struct ClaimFee<'info> {
     /// CHECK: Event authority for lb_clmm
     pub event_authority: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    /// CHECK: The memo program
+    #[account(address = memo::id())]
+    pub memo_program: UncheckedAccount<'info>,
+
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
 }
 
 pub fn claim_fee_handler(ctx: Context<ClaimFee>) -> Result<()> {
-    // TODO: Validation Lb Pair and Strategy token accounts
-
     let strategy_signer = ctx.accounts.strategy.get_pda_signer();
     let strategy_signer_seeds = &[&strategy_signer[..]];
 
-    let accounts = ClaimFeeAccounts {
+    // Claim fees over the whole position range
+    let position_info = ctx.accounts.position.to_account_info();
+    require!(
+        position_info.owner == &dlmm_interface::ID,
+        MaikerError::InvalidPosition
+    );
+    let (min_bin_id, max_bin_id) = {
+        let position_data = position_info.data.borrow();
+        let position = from_bytes::<PositionV2>(&position_data[8..]);
+        (position.lower_bin_id, position.upper_bin_id)
+    };
+
+    let accounts = ClaimFee2Accounts {
         lb_pair: &ctx.accounts.lb_pair.to_account_info(),
-        position: &ctx.accounts.position.to_account_info(),
-        bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
-        bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
+        position: &position_info,
         sender: &ctx.accounts.strategy.to_account_info(),
         reserve_x: &ctx.accounts.reserve_x.to_account_info(),
         reserve_y: &ctx.accounts.reserve_y.to_account_info(),
@@ -92,12 +129,60 @@ pub fn claim_fee_handler(ctx: Context<ClaimFee>) -> Result<()> {
         user_token_y: &ctx.accounts.strategy_vault_y.to_account_info(),
         token_x_mint: &ctx.accounts.token_x_mint.to_account_info(),
         token_y_mint: &ctx.accounts.token_y_mint.to_account_info(),
-        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_program_x: &ctx.accounts.token_x_program.to_account_info(),
+        token_program_y: &ctx.accounts.token_y_program.to_account_info(),
+        memo_program: &ctx.accounts.memo_program.to_account_info(),
         event_authority: &ctx.accounts.event_authority.to_account_info(),
         program: &ctx.accounts.lb_clmm_program.to_account_info(),
     };
 
-    claim_fee_invoke_signed(accounts, strategy_signer_seeds)?;
+    // Transfer hook mints are rejected, so the remaining accounts only hold the bin arrays of the position
+    let args = ClaimFee2IxArgs {
+        min_bin_id,
+        max_bin_id,
+        remaining_accounts_info: RemainingAccountsInfo { slices: vec![] },
+    };
 
-    Ok(())
+    let mut ix = claim_fee2_ix(accounts.into(), args)?;
+    let account_infos: [AccountInfo; CLAIM_FEE2_IX_ACCOUNTS_LEN] = accounts.into();
+    let mut account_infos = account_infos.to_vec();
+
+    let bin_array_lower_info = ctx.accounts.bin_array_lower.to_account_info();
+    let bin_array_upper_info = ctx.accounts.bin_array_upper.to_account_info();
+    ix.accounts
+        .push(AccountMeta::new(bin_array_lower_info.key(), false));
+    account_infos.push(bin_array_lower_info.clone());
+    if bin_array_upper_info.key() != bin_array_lower_info.key() {
+        ix.accounts
+            .push(AccountMeta::new(bin_array_upper_info.key(), false));
+        account_infos.push(bin_array_upper_info);
+    }
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    invoke_signed(&ix, &account_infos, strategy_signer_seeds)?;
+
+    ctx.accounts.strategy_vault_x.reload()?;
+    ctx.accounts.strategy_vault_y.reload()?;
+    let strategy_position = &mut ctx.accounts.strategy_position;
+    strategy_position.add_fees(
+        ctx.accounts
+            .strategy_vault_x
+            .amount
+            .saturating_sub(vault_x_before),
+        ctx.accounts
+            .strategy_vault_y
+            .amount
+            .saturating_sub(vault_y_before),
+    )?;
+
+    revalue_position(
+        &mut ctx.accounts.strategy,
+        strategy_position,
+        &ctx.accounts.position.to_account_info(),
+        &ctx.accounts.lb_pair.to_account_info(),
+        &ctx.accounts.bin_array_lower.to_account_info(),
+        &ctx.accounts.bin_array_upper.to_account_info(),
+    )
 }
@@ -1,11 +1,11 @@
-use crate::state::*;
+use crate::{controllers::record_rebalance, state::*, MaikerError};
 use anchor_lang::prelude::*;
 use dlmm_interface::{close_position_invoke_signed, ClosePositionAccounts};
 
 #[derive(Accounts)]
 pub struct ClosePosition<'info> {
     #[account(
-        constraint = authority.key() == global_config.admin
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c))
     )]
     pub authority: Signer<'info>,
 
@@ -15,6 +15,13 @@ pub struct ClosePosition<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
     #[account(mut)]
     pub strategy: Box<Account<'info, StrategyConfig>>,
 
@@ -22,6 +29,19 @@ pub struct ClosePosition<'info> {
     #[account(mut)]
     pub position: UncheckedAccount<'info>,
 
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [
+            StrategyPosition::SEED_PREFIX.as_bytes(),
+            strategy.key().as_ref(),
+            position.key().as_ref(),
+        ],
+        bump = strategy_position.bump,
+        constraint = strategy_position.has_valid_derivation() @ MaikerError::InvalidPosition
+    )]
+    pub strategy_position: Box<Account<'info, StrategyPosition>>,
+
     /// CHECK: This is the LB pair account
     #[account(mut)]
     pub lb_pair: UncheckedAccount<'info>,
@@ -34,8 +54,8 @@ pub struct ClosePosition<'info> {
     #[account(mut)]
     pub bin_array_upper: UncheckedAccount<'info>,
 
-    /// CHECK: Account to receive the rent from the closed position
-    #[account(mut)]
+    /// CHECK: The strategy creator, receiving the rent of the closed position
+    #[account(mut, address = strategy.creator)]
     pub rent_receiver: UncheckedAccount<'info>,
 
     /// The lb_clmm program
@@ -66,8 +86,7 @@ pub fn close_position_handler(ctx: Context<ClosePosition>) -> Result<()> {
 
     close_position_invoke_signed(accounts, strategy_signer_seeds)?;
 
-    // Update the strategy's positions array by removing the closed position using the remove_position method
-    strategy.remove_position(ctx.accounts.position.key())?;
+    strategy.remove_position(&ctx.accounts.strategy_position)?;
 
-    Ok(())
+    record_rebalance(strategy)
 }
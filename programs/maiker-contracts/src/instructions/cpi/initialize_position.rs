@@ -1,14 +1,15 @@
-use crate::state::*;
+use crate::{controllers::price::validate_lb_pair_info, state::*};
 use anchor_lang::prelude::*;
 use dlmm_interface::{
     initialize_position_invoke_signed, InitializePositionAccounts, InitializePositionIxArgs,
 };
 
 #[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32)]
 pub struct InitializePosition<'info> {
     #[account(
         mut,
-        constraint = authority.key() == global_config.admin
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c))
     )]
     pub authority: Signer<'info>,
 
@@ -18,6 +19,13 @@ pub struct InitializePosition<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
     #[account(mut)]
     pub strategy: Box<Account<'info, StrategyConfig>>,
 
@@ -29,6 +37,19 @@ pub struct InitializePosition<'info> {
     /// CHECK: This is the LB pair account
     pub lb_pair: UncheckedAccount<'info>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StrategyPosition::INIT_SPACE,
+        seeds = [
+            StrategyPosition::SEED_PREFIX.as_bytes(),
+            strategy.key().as_ref(),
+            position.key().as_ref(),
+        ],
+        bump
+    )]
+    pub strategy_position: Box<Account<'info, StrategyPosition>>,
+
     /// The lb_clmm program
     /// CHECK: The lb_clmm program
     #[account(address = dlmm_interface::ID)]
@@ -46,7 +67,7 @@ pub fn initialize_position_handler(
     lower_bin_id: i32,
     width: i32,
 ) -> Result<()> {
-    // TODO: Validation that LB pair is valid for token pair of this strategy
+    validate_lb_pair_info(&ctx.accounts.strategy, &ctx.accounts.lb_pair)?;
     let strategy = &mut ctx.accounts.strategy;
 
     let strategy_signer = strategy.get_pda_signer();
@@ -70,8 +91,19 @@ pub fn initialize_position_handler(
 
     initialize_position_invoke_signed(accounts, args, strategy_signer_seeds)?;
 
-    // Update the strategy's positions array using the add_position method
-    strategy.add_position(ctx.accounts.position.key())?;
+    let strategy_position = &mut ctx.accounts.strategy_position;
+    strategy_position.initialize(
+        strategy.key(),
+        ctx.accounts.position.key(),
+        ctx.accounts.lb_pair.key(),
+        lower_bin_id,
+        lower_bin_id + width - 1,
+        ctx.bumps.strategy_position,
+    );
+    strategy.add_position()?;
+
+    // A freshly initialized position holds no liquidity, so its value is known without a refresh
+    strategy.update_position_value(strategy_position, 0, Clock::get()?.slot)?;
 
     Ok(())
 }
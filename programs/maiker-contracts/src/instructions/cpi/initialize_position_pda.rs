@@ -0,0 +1,124 @@
+use crate::{controllers::price::validate_lb_pair_info, state::*, DLMM_POSITION_SEED};
+use anchor_lang::prelude::*;
+use dlmm_interface::{
+    initialize_position_pda_invoke_signed, InitializePositionPdaAccounts,
+    InitializePositionPdaIxArgs,
+};
+
+#[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32)]
+pub struct InitializePositionPda<'info> {
+    #[account(
+        mut,
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c))
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
+    #[account(mut)]
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    // CPI accounts below
+    /// CHECK: The DLMM position PDA that will be initialized, the strategy is the base
+    #[account(
+        mut,
+        seeds = [
+            DLMM_POSITION_SEED,
+            lb_pair.key().as_ref(),
+            strategy.key().as_ref(),
+            lower_bin_id.to_le_bytes().as_ref(),
+            width.to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = lb_clmm_program.key()
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    /// CHECK: This is the LB pair account
+    pub lb_pair: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StrategyPosition::INIT_SPACE,
+        seeds = [
+            StrategyPosition::SEED_PREFIX.as_bytes(),
+            strategy.key().as_ref(),
+            position.key().as_ref(),
+        ],
+        bump
+    )]
+    pub strategy_position: Box<Account<'info, StrategyPosition>>,
+
+    /// The lb_clmm program
+    /// CHECK: The lb_clmm program
+    #[account(address = dlmm_interface::ID)]
+    pub lb_clmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: This is the event authority for lb_clmm
+    pub event_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_position_pda_handler(
+    ctx: Context<InitializePositionPda>,
+    lower_bin_id: i32,
+    width: i32,
+) -> Result<()> {
+    validate_lb_pair_info(&ctx.accounts.strategy, &ctx.accounts.lb_pair)?;
+    let strategy = &mut ctx.accounts.strategy;
+
+    let strategy_signer = strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let args = InitializePositionPdaIxArgs {
+        lower_bin_id,
+        width,
+    };
+
+    // The strategy signs as both the base of the position PDA and its owner
+    let accounts = InitializePositionPdaAccounts {
+        payer: &ctx.accounts.authority.to_account_info(),
+        base: &strategy.to_account_info(),
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        owner: &strategy.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        rent: &ctx.accounts.rent.to_account_info(),
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    initialize_position_pda_invoke_signed(accounts, args, strategy_signer_seeds)?;
+
+    let strategy_position = &mut ctx.accounts.strategy_position;
+    strategy_position.initialize(
+        strategy.key(),
+        ctx.accounts.position.key(),
+        ctx.accounts.lb_pair.key(),
+        lower_bin_id,
+        lower_bin_id + width - 1,
+        ctx.bumps.strategy_position,
+    );
+    strategy_position.set_derived(ctx.bumps.position);
+    strategy.add_position()?;
+
+    // A freshly initialized position holds no liquidity, so its value is known without a refresh
+    strategy.update_position_value(strategy_position, 0, Clock::get()?.slot)?;
+
+    Ok(())
+}
@@ -1,13 +1,19 @@
 pub mod add_liquidity;
+pub mod add_liquidity_by_strategy;
+pub mod add_liquidity_one_side;
 pub mod claim_fee;
 pub mod close_position;
 pub mod initialize_position;
+pub mod initialize_position_pda;
 pub mod remove_liquidity;
 pub mod swap;
 
 pub use add_liquidity::*;
+pub use add_liquidity_by_strategy::*;
+pub use add_liquidity_one_side::*;
 pub use claim_fee::*;
 pub use close_position::*;
 pub use initialize_position::*;
+pub use initialize_position_pda::*;
 pub use remove_liquidity::*;
 pub use swap::*;
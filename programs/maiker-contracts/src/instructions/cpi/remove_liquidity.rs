@@ -1,14 +1,37 @@
-use crate::state::*;
+use crate::{controllers::valuation::revalue_position, memo, state::*, MaikerError};
 use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use dlmm_interface::{
-    remove_all_liquidity_ix, RemoveAllLiquidityAccounts, REMOVE_ALL_LIQUIDITY_IX_ACCOUNTS_LEN,
+    remove_all_liquidity_ix, remove_liquidity_by_range2_ix,
+    remove_liquidity_by_range_invoke_signed, remove_liquidity_invoke_signed, RemainingAccountsInfo,
+    RemoveAllLiquidityAccounts, RemoveLiquidityAccounts as DlmmRemoveLiquidityAccounts,
+    RemoveLiquidityByRange2Accounts, RemoveLiquidityByRange2IxArgs, RemoveLiquidityByRangeAccounts,
+    RemoveLiquidityByRangeIxArgs, RemoveLiquidityIxArgs, REMOVE_ALL_LIQUIDITY_IX_ACCOUNTS_LEN,
+    REMOVE_LIQUIDITY_BY_RANGE2_IX_ACCOUNTS_LEN,
 };
 
+// Copy from lb_clmm
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug, Default)]
+pub struct BinLiquidityReduction {
+    /// Bin ID to remove liquidity from
+    pub bin_id: i32,
+    /// Share of the bin liquidity to remove in basis points
+    pub bps_to_remove: u16,
+}
+
+impl From<BinLiquidityReduction> for dlmm_interface::BinLiquidityReduction {
+    fn from(reduction: BinLiquidityReduction) -> Self {
+        Self {
+            bin_id: reduction.bin_id,
+            bps_to_remove: reduction.bps_to_remove,
+        }
+    }
+}
+
 #[derive(Accounts)]
 pub struct RemoveLiquidity<'info> {
     #[account(
-        constraint = authority.key() == global_config.admin
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c))
     )]
     pub authority: Signer<'info>,
 
@@ -18,6 +41,13 @@ pub struct RemoveLiquidity<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
     #[account(mut)]
     pub strategy: Box<Account<'info, StrategyConfig>>,
 
@@ -26,19 +56,32 @@ pub struct RemoveLiquidity<'info> {
         token::mint = token_x_mint,
         token::authority = strategy
     )]
-    pub strategy_vault_x: Account<'info, TokenAccount>,
+    pub strategy_vault_x: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         token::mint = token_y_mint,
         token::authority = strategy
     )]
-    pub strategy_vault_y: Account<'info, TokenAccount>,
+    pub strategy_vault_y: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: This is the position account
     #[account(mut)]
     pub position: UncheckedAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [
+            StrategyPosition::SEED_PREFIX.as_bytes(),
+            strategy.key().as_ref(),
+            position.key().as_ref(),
+        ],
+        bump = strategy_position.bump,
+        has_one = lb_pair,
+        constraint = strategy_position.has_valid_derivation() @ MaikerError::InvalidPosition
+    )]
+    pub strategy_position: Box<Account<'info, StrategyPosition>>,
+
     /// CHECK: This is the LB pair account
     #[account(mut)]
     pub lb_pair: UncheckedAccount<'info>,
@@ -77,21 +120,19 @@ pub struct RemoveLiquidity<'info> {
     /// CHECK: Event authority for lb_clmm
     pub event_authority: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: The memo program, required by remove_liquidity_by_range2
+    #[account(address = memo::id())]
+    pub memo_program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn remove_all_liquidity_handler(ctx: Context<RemoveLiquidity>) -> Result<()> {
-    // TODO: Validation Lb Pair and Strategy token accounts
-
     let strategy_signer = ctx.accounts.strategy.get_pda_signer();
     let strategy_signer_seeds = &[&strategy_signer[..]];
 
-    let bin_array_bitmap_extension_info =
-        if let Some(account) = &ctx.accounts.bin_array_bitmap_extension {
-            account.to_account_info()
-        } else {
-            ctx.accounts.lb_clmm_program.to_account_info()
-        };
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
 
     // Create the accounts for the CPI call
     let accounts = RemoveAllLiquidityAccounts {
@@ -107,8 +148,8 @@ pub fn remove_all_liquidity_handler(ctx: Context<RemoveLiquidity>) -> Result<()>
         bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
         bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
         sender: &ctx.accounts.strategy.to_account_info(),
-        token_x_program: &ctx.accounts.token_program.to_account_info(),
-        token_y_program: &ctx.accounts.token_program.to_account_info(),
+        token_x_program: &ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: &ctx.accounts.token_y_program.to_account_info(),
         event_authority: &ctx.accounts.event_authority.to_account_info(),
         program: &ctx.accounts.lb_clmm_program.to_account_info(),
     };
@@ -121,5 +162,225 @@ pub fn remove_all_liquidity_handler(ctx: Context<RemoveLiquidity>) -> Result<()>
 
     invoke_signed(&ix, &account_infos, strategy_signer_seeds)?;
 
-    Ok(())
+    ctx.accounts.strategy_position.remove_all_liquidity();
+
+    ctx.accounts.revalue_position()
+}
+
+// Removes bps_to_remove of the liquidity of every bin from from_bin_id to to_bin_id
+pub fn remove_liquidity_by_range_handler(
+    ctx: Context<RemoveLiquidity>,
+    from_bin_id: i32,
+    to_bin_id: i32,
+    bps_to_remove: u16,
+) -> Result<()> {
+    ctx.accounts
+        .strategy_position
+        .validate_liquidity_reduction(from_bin_id, to_bin_id, bps_to_remove)?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+
+    let accounts = RemoveLiquidityByRangeAccounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token_x: &ctx.accounts.strategy_vault_x.to_account_info(),
+        user_token_y: &ctx.accounts.strategy_vault_y.to_account_info(),
+        reserve_x: &ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: &ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: &ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: &ctx.accounts.token_y_mint.to_account_info(),
+        bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_x_program: &ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: &ctx.accounts.token_y_program.to_account_info(),
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    let args = RemoveLiquidityByRangeIxArgs {
+        from_bin_id,
+        to_bin_id,
+        bps_to_remove,
+    };
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    remove_liquidity_by_range_invoke_signed(accounts, args, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .remove_liquidity_from_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+// Removes bps_to_remove of the liquidity of every bin from from_bin_id to to_bin_id, supporting Token-2022 pairs
+pub fn remove_liquidity_by_range2_handler(
+    ctx: Context<RemoveLiquidity>,
+    from_bin_id: i32,
+    to_bin_id: i32,
+    bps_to_remove: u16,
+) -> Result<()> {
+    ctx.accounts
+        .strategy_position
+        .validate_liquidity_reduction(from_bin_id, to_bin_id, bps_to_remove)?;
+
+    let memo_program_info = ctx
+        .accounts
+        .memo_program
+        .as_ref()
+        .map(|memo_program| memo_program.to_account_info())
+        .ok_or(MaikerError::MemoProgramRequired)?;
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+
+    let accounts = RemoveLiquidityByRange2Accounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token_x: &ctx.accounts.strategy_vault_x.to_account_info(),
+        user_token_y: &ctx.accounts.strategy_vault_y.to_account_info(),
+        reserve_x: &ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: &ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: &ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: &ctx.accounts.token_y_mint.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_x_program: &ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: &ctx.accounts.token_y_program.to_account_info(),
+        memo_program: &memo_program_info,
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    // Transfer hook mints are rejected, so the remaining accounts only hold the bin arrays of the position
+    let args = RemoveLiquidityByRange2IxArgs {
+        from_bin_id,
+        to_bin_id,
+        bps_to_remove,
+        remaining_accounts_info: RemainingAccountsInfo { slices: vec![] },
+    };
+
+    let mut ix = remove_liquidity_by_range2_ix(accounts.into(), args)?;
+    let account_infos: [AccountInfo; REMOVE_LIQUIDITY_BY_RANGE2_IX_ACCOUNTS_LEN] = accounts.into();
+    let mut account_infos = account_infos.to_vec();
+
+    let bin_array_lower_info = ctx.accounts.bin_array_lower.to_account_info();
+    let bin_array_upper_info = ctx.accounts.bin_array_upper.to_account_info();
+    ix.accounts
+        .push(AccountMeta::new(bin_array_lower_info.key(), false));
+    account_infos.push(bin_array_lower_info.clone());
+    if bin_array_upper_info.key() != bin_array_lower_info.key() {
+        ix.accounts
+            .push(AccountMeta::new(bin_array_upper_info.key(), false));
+        account_infos.push(bin_array_upper_info);
+    }
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    invoke_signed(&ix, &account_infos, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .remove_liquidity_from_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+// Removes a bps of the liquidity of each listed bin
+pub fn remove_liquidity_by_bins_handler(
+    ctx: Context<RemoveLiquidity>,
+    bin_liquidity_removal: Vec<BinLiquidityReduction>,
+) -> Result<()> {
+    for reduction in &bin_liquidity_removal {
+        ctx.accounts
+            .strategy_position
+            .validate_liquidity_reduction(
+                reduction.bin_id,
+                reduction.bin_id,
+                reduction.bps_to_remove,
+            )?;
+    }
+
+    let strategy_signer = ctx.accounts.strategy.get_pda_signer();
+    let strategy_signer_seeds = &[&strategy_signer[..]];
+
+    let bin_array_bitmap_extension_info = ctx.accounts.bin_array_bitmap_extension_info();
+
+    let accounts = DlmmRemoveLiquidityAccounts {
+        position: &ctx.accounts.position.to_account_info(),
+        lb_pair: &ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: &bin_array_bitmap_extension_info,
+        user_token_x: &ctx.accounts.strategy_vault_x.to_account_info(),
+        user_token_y: &ctx.accounts.strategy_vault_y.to_account_info(),
+        reserve_x: &ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: &ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: &ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: &ctx.accounts.token_y_mint.to_account_info(),
+        bin_array_lower: &ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: &ctx.accounts.bin_array_upper.to_account_info(),
+        sender: &ctx.accounts.strategy.to_account_info(),
+        token_x_program: &ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: &ctx.accounts.token_y_program.to_account_info(),
+        event_authority: &ctx.accounts.event_authority.to_account_info(),
+        program: &ctx.accounts.lb_clmm_program.to_account_info(),
+    };
+
+    let args = RemoveLiquidityIxArgs {
+        bin_liquidity_removal: bin_liquidity_removal.into_iter().map(Into::into).collect(),
+    };
+
+    let vault_x_before = ctx.accounts.strategy_vault_x.amount;
+    let vault_y_before = ctx.accounts.strategy_vault_y.amount;
+
+    remove_liquidity_invoke_signed(accounts, args, strategy_signer_seeds)?;
+
+    ctx.accounts
+        .remove_liquidity_from_cost_basis(vault_x_before, vault_y_before)?;
+
+    ctx.accounts.revalue_position()
+}
+
+impl<'info> RemoveLiquidity<'info> {
+    fn bin_array_bitmap_extension_info(&self) -> AccountInfo<'info> {
+        if let Some(account) = &self.bin_array_bitmap_extension {
+            account.to_account_info()
+        } else {
+            self.lb_clmm_program.to_account_info()
+        }
+    }
+
+    /// Lowers the position cost basis by the tokens the removal paid into the vaults
+    fn remove_liquidity_from_cost_basis(
+        &mut self,
+        vault_x_before: u64,
+        vault_y_before: u64,
+    ) -> Result<()> {
+        self.strategy_vault_x.reload()?;
+        self.strategy_vault_y.reload()?;
+        self.strategy_position.remove_liquidity(
+            self.strategy_vault_x.amount.saturating_sub(vault_x_before),
+            self.strategy_vault_y.amount.saturating_sub(vault_y_before),
+        );
+
+        Ok(())
+    }
+
+    fn revalue_position(&mut self) -> Result<()> {
+        revalue_position(
+            &mut self.strategy,
+            &mut self.strategy_position,
+            &self.position.to_account_info(),
+            &self.lb_pair.to_account_info(),
+            &self.bin_array_lower.to_account_info(),
+            &self.bin_array_upper.to_account_info(),
+        )
+    }
 }
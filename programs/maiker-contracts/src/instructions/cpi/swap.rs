@@ -2,17 +2,17 @@ use anchor_lang::{
     prelude::*,
     solana_program::{instruction::Instruction, program::invoke_signed},
 };
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use dlmm_interface::{SwapAccounts, SwapIxArgs, SwapIxData, SwapKeys, SWAP_IX_ACCOUNTS_LEN};
 
-use crate::{GlobalConfig, StrategyConfig};
+use crate::{controllers::record_rebalance, GlobalConfig, StrategyConfig, StrategyCreator};
 
 #[derive(Accounts)]
 pub struct Swap<'info> {
     /// The authority of the strategy
     #[account(
         mut,
-        constraint = authority.key() == global_config.admin
+        constraint = strategy.is_operator(authority.key(), &global_config, strategy_creator.as_deref().map(|c| &**c))
     )]
     pub authority: Signer<'info>,
 
@@ -22,6 +22,13 @@ pub struct Swap<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Registry entry of the strategy creator, required unless the authority is the admin
+    #[account(
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), strategy.creator.as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
     #[account(mut)]
     pub strategy: Box<Account<'info, StrategyConfig>>,
 
@@ -43,11 +50,11 @@ pub struct Swap<'info> {
 
     /// The strategy vault for token X, which will be used for swapping
     #[account(mut)]
-    pub strategy_vault_x: Account<'info, TokenAccount>,
+    pub strategy_vault_x: InterfaceAccount<'info, TokenAccount>,
 
     /// The strategy vault for token Y, which will be used for swapping
     #[account(mut)]
-    pub strategy_vault_y: Account<'info, TokenAccount>,
+    pub strategy_vault_y: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: Token X mint
     pub token_x_mint: UncheckedAccount<'info>,
@@ -72,10 +79,10 @@ pub struct Swap<'info> {
     pub event_authority: UncheckedAccount<'info>,
 
     /// The token program for token X
-    pub token_x_program: Program<'info, Token>,
+    pub token_x_program: Interface<'info, TokenInterface>,
 
     /// The token program for token Y
-    pub token_y_program: Program<'info, Token>,
+    pub token_y_program: Interface<'info, TokenInterface>,
 }
 
 /// Handle exact input swap - specifies the exact input amount and a minimum output amount
@@ -184,5 +191,5 @@ pub fn swap_exact_in_handler<'a, 'b, 'c, 'info>(
 
     invoke_signed(&ix, &accs, strategy_signer_seeds)?;
 
-    Ok(())
+    record_rebalance(&mut ctx.accounts.strategy)
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{controllers::migration::migrate_account, state::*, MigrateAccountEvent};
+
+/// Upgrades a GlobalConfig written before accounts were versioned. Anyone may pay for the upgrade,
+/// it only changes the layout of the account.
+#[derive(Accounts)]
+pub struct MigrateGlobalConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Written with the legacy layout, decoded and upgraded by the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump,
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_global_config_handler(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+    let global_config = migrate_account::<GlobalConfigV0, GlobalConfig>(
+        &ctx.accounts.global_config.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(MigrateAccountEvent {
+        account: ctx.accounts.global_config.key(),
+        version: global_config.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    controllers::migration::migrate_account, state::*, validate, MaikerError, MigrateAccountEvent,
+};
+
+/// Upgrades a PendingWithdrawal written before accounts were versioned. Anyone may pay for the upgrade,
+/// it counts the withdrawal on its strategy, since legacy strategies did not count pending withdrawals.
+/// The strategy has to be migrated first.
+#[derive(Accounts)]
+pub struct MigratePendingWithdrawal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Written with the legacy layout, decoded and upgraded by the handler
+    #[account(mut, owner = crate::ID)]
+    pub pending_withdrawal: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_pending_withdrawal_handler(ctx: Context<MigratePendingWithdrawal>) -> Result<()> {
+    let pending_withdrawal = migrate_account::<PendingWithdrawalV0, PendingWithdrawal>(
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    validate!(
+        pending_withdrawal.strategy == ctx.accounts.strategy.key(),
+        MaikerError::LegacyAccountMismatch,
+        "Pending withdrawal does not belong to the strategy"
+    )?;
+    ctx.accounts.strategy.add_pending_withdrawal()?;
+
+    emit!(MigrateAccountEvent {
+        account: ctx.accounts.pending_withdrawal.key(),
+        version: pending_withdrawal.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{
+    controllers::migration::{
+        create_legacy_strategy_position, decode_legacy_account, write_migrated_account,
+    },
+    state::*,
+    validate, MaikerError, MigrateAccountEvent, ANCHOR_DISCRIMINATOR,
+};
+
+/// Accounts expected in `remaining_accounts` per position tracked by the legacy strategy, in the order of
+/// its positions array: position, strategy_position (the StrategyPosition PDA created for it)
+pub const MIGRATE_ACCOUNTS_PER_POSITION: usize = 2;
+
+/// Upgrades a StrategyConfig written before accounts were versioned. Anyone may pay for the upgrade,
+/// it registers the strategy in the strategy registry, creates its share mint and moves its positions to StrategyPosition PDAs.
+#[derive(Accounts)]
+pub struct MigrateStrategyConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: Written with the legacy layout, decoded and upgraded by the handler
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    /// Checked against the legacy strategy by the handler
+    pub x_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Checked against the legacy strategy by the handler
+    pub y_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Legacy strategies tracked shares without a mint
+    #[account(
+        init,
+        payer = payer,
+        seeds = [StrategyConfig::SHARE_MINT_SEED_PREFIX.as_bytes(), strategy.key().as_ref()],
+        bump,
+        mint::decimals = x_mint.decimals,
+        mint::authority = strategy,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Registry entry of the strategy, at the next strategy index
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + StrategyRegistryEntry::INIT_SPACE,
+        seeds = [
+            StrategyRegistryEntry::SEED_PREFIX.as_bytes(),
+            global_config.strategy_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub strategy_registry_entry: Box<Account<'info, StrategyRegistryEntry>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_strategy_config_handler<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, MigrateStrategyConfig<'info>>,
+) -> Result<()> {
+    let strategy_info = ctx.accounts.strategy.to_account_info();
+    let legacy = decode_legacy_account::<StrategyConfigV0, StrategyConfig>(
+        &strategy_info.try_borrow_data()?,
+    )?;
+
+    validate!(
+        ctx.accounts.x_mint.key() == legacy.x_mint && ctx.accounts.y_mint.key() == legacy.y_mint,
+        MaikerError::InvalidMint,
+        "Mints do not match the strategy"
+    )?;
+
+    let positions = legacy.tracked_positions();
+    require!(
+        ctx.remaining_accounts.len() == positions.len() * MIGRATE_ACCOUNTS_PER_POSITION,
+        MaikerError::InvalidRemainingAccounts
+    );
+
+    let mut strategy = StrategyConfig::from(legacy);
+    let strategy_index = ctx.accounts.global_config.add_strategy()?;
+    ctx.accounts
+        .strategy_registry_entry
+        .initialize(strategy_info.key(), ctx.bumps.strategy_registry_entry);
+    strategy.set_strategy_id(0, strategy_index);
+    strategy.set_token_decimals(ctx.accounts.x_mint.decimals, ctx.accounts.y_mint.decimals);
+    strategy.set_share_mint(ctx.accounts.share_mint.key());
+
+    write_migrated_account(
+        &strategy_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &strategy,
+    )?;
+
+    for (legacy_position, accounts) in positions
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(MIGRATE_ACCOUNTS_PER_POSITION))
+    {
+        let [position, strategy_position] = accounts else {
+            return Err(MaikerError::InvalidRemainingAccounts.into());
+        };
+
+        create_legacy_strategy_position(
+            strategy_info.key(),
+            legacy_position,
+            position,
+            strategy_position,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    emit!(MigrateAccountEvent {
+        account: strategy_info.key(),
+        version: strategy.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
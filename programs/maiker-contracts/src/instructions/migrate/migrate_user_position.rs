@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    controllers::migration::{decode_legacy_account, write_migrated_account},
+    state::*,
+    validate, MaikerError, MigrateAccountEvent,
+};
+
+/// Upgrades a UserPosition written before accounts were versioned. Anyone may pay for the upgrade,
+/// it mints the shares of the position to the user, since legacy positions tracked shares without a mint.
+/// The strategy has to be migrated first.
+#[derive(Accounts)]
+pub struct MigrateUserPosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Written with the legacy layout, decoded and upgraded by the handler
+    #[account(mut, owner = crate::ID)]
+    pub user_position: UncheckedAccount<'info>,
+
+    /// CHECK: Owner of the user position, checked by the handler
+    pub user: UncheckedAccount<'info>,
+
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    #[account(
+        mut,
+        address = strategy.share_mint,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_share_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_user_position_handler(ctx: Context<MigrateUserPosition>) -> Result<()> {
+    let user_position_info = ctx.accounts.user_position.to_account_info();
+    let legacy = decode_legacy_account::<UserPositionV0, UserPosition>(
+        &user_position_info.try_borrow_data()?,
+    )?;
+
+    validate!(
+        legacy.user == ctx.accounts.user.key() && legacy.strategy == ctx.accounts.strategy.key(),
+        MaikerError::LegacyAccountMismatch,
+        "User position does not belong to the user and strategy"
+    )?;
+
+    let user_position = UserPosition::from(legacy);
+    write_migrated_account(
+        &user_position_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &user_position,
+    )?;
+
+    // Share tokens are synced with the tracked shares by every later user instruction
+    if user_position.strategy_share > 0 {
+        let strategy = &ctx.accounts.strategy;
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.share_mint.to_account_info(),
+                    to: ctx.accounts.user_share_token.to_account_info(),
+                    authority: strategy.to_account_info(),
+                },
+                &[&strategy.get_pda_signer()],
+            ),
+            user_position.strategy_share,
+        )?;
+    }
+
+    emit!(MigrateAccountEvent {
+        account: user_position_info.key(),
+        version: user_position.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
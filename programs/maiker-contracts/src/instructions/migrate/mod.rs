@@ -0,0 +1,9 @@
+pub mod migrate_global_config;
+pub mod migrate_pending_withdrawal;
+pub mod migrate_strategy_config;
+pub mod migrate_user_position;
+
+pub use migrate_global_config::*;
+pub use migrate_pending_withdrawal::*;
+pub use migrate_strategy_config::*;
+pub use migrate_user_position::*;
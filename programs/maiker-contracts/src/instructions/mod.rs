@@ -1,9 +1,11 @@
 pub mod admin;
 pub mod cpi;
 pub mod initialize;
+pub mod migrate;
 pub mod user;
 
 pub use admin::*;
 pub use cpi::*;
 pub use initialize::*;
+pub use migrate::*;
 pub use user::*;
@@ -0,0 +1,69 @@
+use crate::{state::*, CloseUserPositionEvent, MaikerError};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CloseUserPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), user.key().as_ref(), strategy.key().as_ref()],
+        bump = user_position.bump,
+        close = user
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    /// CHECK: Must not be initialized, a pending withdrawal keeps the position open
+    #[account(
+        seeds = [PendingWithdrawal::SEED_PREFIX.as_bytes(), user.key().as_ref(), strategy.key().as_ref()],
+        bump
+    )]
+    pub pending_withdrawal: UncheckedAccount<'info>,
+
+    #[account(
+        address = strategy.share_mint,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_share_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn close_user_position_handler(ctx: Context<CloseUserPosition>) -> Result<()> {
+    // Only empty positions can be closed. Tracked shares only lag behind share token transfers,
+    // so the share token balance is the position's shares
+    require!(
+        ctx.accounts.user_share_token.amount == 0,
+        MaikerError::UserPositionNotEmpty
+    );
+
+    // The pending withdrawal must be processed first
+    require!(
+        ctx.accounts.pending_withdrawal.data_is_empty(),
+        MaikerError::PendingWithdrawalExists
+    );
+
+    // The account is zeroed on close, so a new deposit starts from the initial share value
+    // instead of a stale last_share_value
+
+    // Emit event
+    emit!(CloseUserPositionEvent {
+        user: ctx.accounts.user.key(),
+        strategy: ctx.accounts.strategy.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
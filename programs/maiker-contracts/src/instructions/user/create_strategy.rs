@@ -1,29 +1,50 @@
-use crate::{state::*, CreateStrategyEvent, ANCHOR_DISCRIMINATOR};
+use crate::{controllers, state::*, CreateStrategyEvent, MaikerError, ANCHOR_DISCRIMINATOR};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 #[derive(Accounts)]
+#[instruction(strategy_id: u16)]
 pub struct CreateStrategy<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    pub x_mint: Account<'info, Mint>,
-    pub y_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Required unless the creator is the admin
+    #[account(
+        mut,
+        seeds = [StrategyCreator::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump = strategy_creator.bump,
+    )]
+    pub strategy_creator: Option<Box<Account<'info, StrategyCreator>>>,
+
+    #[account(mint::token_program = token_x_program)]
+    pub x_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_y_program)]
+    pub y_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         associated_token::mint = x_mint,
         associated_token::authority = strategy,
+        associated_token::token_program = token_x_program,
     )]
-    pub x_vault: Account<'info, TokenAccount>,
+    pub x_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         associated_token::mint = y_mint,
         associated_token::authority = strategy,
+        associated_token::token_program = token_y_program,
     )]
-    pub y_vault: Account<'info, TokenAccount>,
+    pub y_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
@@ -33,23 +54,74 @@ pub struct CreateStrategy<'info> {
             StrategyConfig::SEED_PREFIX.as_bytes(),
             creator.key().as_ref(),
             x_mint.key().as_ref(),
-            y_mint.key().as_ref()
+            y_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref()
         ],
         bump
     )]
-    pub strategy: Account<'info, StrategyConfig>,
+    pub strategy: Box<Account<'info, StrategyConfig>>,
 
-    pub token_program: Program<'info, Token>,
+    /// Registry entry of the strategy, at the next strategy index
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR + StrategyRegistryEntry::INIT_SPACE,
+        seeds = [
+            StrategyRegistryEntry::SEED_PREFIX.as_bytes(),
+            global_config.strategy_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub strategy_registry_entry: Box<Account<'info, StrategyRegistryEntry>>,
+
+    /// Already exists when a closed strategy is recreated
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [StrategyConfig::SHARE_MINT_SEED_PREFIX.as_bytes(), strategy.key().as_ref()],
+        bump,
+        mint::decimals = x_mint.decimals,
+        mint::authority = strategy,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_strategy_handler(ctx: Context<CreateStrategy>) -> Result<()> {
+pub fn create_strategy_handler(ctx: Context<CreateStrategy>, strategy_id: u16) -> Result<()> {
     let strategy = &mut ctx.accounts.strategy;
     let clock = Clock::get()?;
 
     let strategy_bump = ctx.bumps.strategy;
 
+    // Only the admin and approved creators may create strategies
+    match ctx.accounts.strategy_creator.as_mut() {
+        Some(strategy_creator) => strategy_creator.add_strategy()?,
+        None => require!(
+            ctx.accounts.creator.key() == ctx.accounts.global_config.admin,
+            MaikerError::CreatorNotApproved
+        ),
+    }
+
+    // Register the strategy globally
+    let strategy_index = ctx.accounts.global_config.add_strategy()?;
+    ctx.accounts
+        .strategy_registry_entry
+        .initialize(strategy.key(), ctx.bumps.strategy_registry_entry);
+
+    // Reject mints whose transfers can not be performed by the strategy
+    controllers::token::validate_mint_transfer_hook(&ctx.accounts.x_mint.to_account_info())?;
+    controllers::token::validate_mint_transfer_hook(&ctx.accounts.y_mint.to_account_info())?;
+
+    // The share mint of a closed strategy at this address is taken over
+    controllers::token::validate_reused_share_mint(&ctx.accounts.share_mint, strategy.key())?;
+
     // Initialize strategy
     strategy.initialize_strategy(
         ctx.accounts.creator.key(),
@@ -59,6 +131,9 @@ pub fn create_strategy_handler(ctx: Context<CreateStrategy>) -> Result<()> {
         ctx.accounts.y_vault.key(),
         strategy_bump,
     );
+    strategy.set_strategy_id(strategy_id, strategy_index);
+    strategy.set_token_decimals(ctx.accounts.x_mint.decimals, ctx.accounts.y_mint.decimals);
+    strategy.set_share_mint(ctx.accounts.share_mint.key());
 
     // Emit event
     emit!(CreateStrategyEvent {
@@ -66,6 +141,9 @@ pub fn create_strategy_handler(ctx: Context<CreateStrategy>) -> Result<()> {
         creator: ctx.accounts.creator.key(),
         x_mint: strategy.x_mint,
         y_mint: strategy.y_mint,
+        strategy_id,
+        strategy_index,
+        share_mint: strategy.share_mint,
         timestamp: clock.unix_timestamp,
     });
 
@@ -1,8 +1,12 @@
 use crate::{
-    error::MaikerError, state::*, UserDepositEvent, ANCHOR_DISCRIMINATOR, SHARE_PRECISION,
+    controllers, error::MaikerError, math::u128x128_math::Rounding, state::*, validate,
+    UserDepositEvent, ANCHOR_DISCRIMINATOR, DEAD_SHARES, SHARE_PRECISION,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface},
+};
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
@@ -27,112 +31,198 @@ pub struct Deposit<'info> {
     )]
     pub user_position: Box<Account<'info, UserPosition>>,
 
+    /// Required when the strategy only allows allowlisted depositors
+    #[account(
+        seeds = [DepositorAllowance::SEED_PREFIX.as_bytes(), strategy.key().as_ref(), user.key().as_ref()],
+        bump = depositor_allowance.bump,
+    )]
+    pub depositor_allowance: Option<Box<Account<'info, DepositorAllowance>>>,
+
     #[account(
         mut,
         constraint = user_token_x.mint == strategy.x_mint,
         constraint = user_token_x.owner == user.key()
     )]
-    pub user_token_x: Box<Account<'info, TokenAccount>>,
+    pub user_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         constraint = strategy_vault_x.key() == strategy.x_vault
     )]
-    pub strategy_vault_x: Box<Account<'info, TokenAccount>>,
+    pub strategy_vault_x: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = strategy.x_mint,
+        mint::token_program = token_x_program,
+    )]
+    pub x_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        address = strategy.share_mint,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_share_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn deposit_handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+pub fn deposit_handler(
+    ctx: Context<Deposit>,
+    amount: u64,
+    min_shares_out: u64,
+    merkle_proof: Option<Vec<[u8; 32]>>,
+) -> Result<()> {
     let strategy = &mut ctx.accounts.strategy;
     let user_position = &mut ctx.accounts.user_position;
     let clock = Clock::get()?;
     let slot = clock.slot;
 
-    // Validate that all positions have up-to-date values
-    strategy.validate_position_values_freshness(slot)?;
+    // Validate that the cached strategy value is up-to-date
+    strategy.validate_strategy_value_freshness(slot)?;
 
     // Ensure token amount is greater than zero
     require!(amount > 0, MaikerError::InvalidDepositAmount);
 
+    // Ensure the user may deposit into the strategy
+    let is_allowed = match strategy.deposit_access_mode {
+        DepositAccessMode::Open => true,
+        DepositAccessMode::Allowlist => ctx.accounts.depositor_allowance.is_some(),
+        DepositAccessMode::MerkleRoot => merkle_proof.as_deref().is_some_and(|proof| {
+            controllers::merkle::verify_merkle_proof(
+                proof,
+                &strategy.deposit_merkle_root,
+                controllers::merkle::get_depositor_leaf(&ctx.accounts.user.key()),
+            )
+        }),
+    };
+    require!(is_allowed, MaikerError::DepositorNotAllowed);
+
+    // Get the vault balance before this deposit
+    let vault_x_balance = ctx.accounts.strategy_vault_x.amount;
+    msg!("Vault balance: {}", vault_x_balance);
+
+    // Transfer tokens from user to strategy vault
+    controllers::token::receive(
+        &ctx.accounts.token_x_program,
+        &ctx.accounts.user_token_x,
+        &ctx.accounts.strategy_vault_x,
+        &ctx.accounts.user,
+        amount,
+        &ctx.accounts.x_mint,
+        ctx.accounts
+            .global_config
+            .is_transfer_fee_mint_allowed(&strategy.x_mint),
+    )?;
+
+    // Only the amount received by the vault is credited, net of any transfer fee
+    ctx.accounts.strategy_vault_x.reload()?;
+    let received_amount = ctx
+        .accounts
+        .strategy_vault_x
+        .amount
+        .checked_sub(vault_x_balance)
+        .ok_or(MaikerError::ArithmeticOverflow)?;
+    msg!("Received amount: {}", received_amount);
+    require!(received_amount > 0, MaikerError::InvalidDepositAmount);
+
+    // TODO: Make sure vault y balance is also accounted for
+    // let vault_y_balance = ctx.accounts.strategy_vault_y.amount;
+    // msg!("Vault balance: {}", vault_y_balance);
+
+    // Calculate total strategy value including positions
+    let total_strategy_value = strategy.calculate_total_strategy_value(vault_x_balance)?;
+    msg!("Total strategy value: {}", total_strategy_value);
+
+    // Enforce the minimum deposit and the strategy deposit cap
+    strategy.validate_deposit_limits(received_amount, total_strategy_value)?;
+
+    // Charge the performance fee on the gain since the last charge, before pricing the deposit
+    let performance_fee_shares = strategy.crystallize_performance_fee(
+        total_strategy_value,
+        ctx.accounts.global_config.performance_fee_bps,
+    )?;
+    msg!("Performance fee shares: {}", performance_fee_shares);
+
     // Calculate shares to mint
     let new_shares: u64;
-    let current_share_value: u64;
-    let mut performance_fee_shares: u64 = 0;
+    let current_share_value: u128;
 
     // Calculate shares to mint and current share value
     if strategy.strategy_shares == 0 {
-        // Initial deposit case - set initial share price to 1:1
-        new_shares = amount;
+        // Initial deposit case - set initial share price to 1:1 and lock the dead shares
+        new_shares = strategy.calculate_shares_for_initial_deposit(received_amount)?;
         current_share_value = SHARE_PRECISION; // 1:1
+        strategy.mint_shares(DEAD_SHARES)?;
     } else {
-        // Get the total value of the strategy before this deposit
-        let vault_x_balance = ctx.accounts.strategy_vault_x.amount;
-        msg!("Vault balance: {}", vault_x_balance);
-
-        // TODO: Make sure vault y balance is also accounted for
-        // let vault_y_balance = ctx.accounts.strategy_vault_y.amount;
-        // msg!("Vault balance: {}", vault_y_balance);
-
-        // Calculate total strategy value including positions
-        let total_strategy_value = strategy.calculate_total_strategy_value(vault_x_balance)?;
-        msg!("Total strategy value: {}", total_strategy_value);
-
-        // Calculate the current share value
-        current_share_value = strategy.calculate_share_value(total_strategy_value)?;
+        // Calculate the current share value, rounded up so the deposit mints shares rounded down
+        current_share_value = strategy.calculate_share_value(total_strategy_value, Rounding::Up)?;
         msg!("Current share value: {}", current_share_value);
 
         // Calculate new shares based on deposit value and current share value
-        new_shares = strategy.calculate_shares_for_deposit(amount, current_share_value)?;
+        new_shares = strategy.calculate_shares_for_deposit(received_amount, current_share_value)?;
     }
 
+    // Protect the user against share value changes since signing
+    validate!(
+        new_shares >= min_shares_out,
+        MaikerError::SlippageExceeded,
+        "Shares out {} below minimum {}",
+        new_shares,
+        min_shares_out
+    )?;
+
     // Update strategy shares
     msg!("Minting shares: {}", new_shares);
     strategy.mint_shares(new_shares)?;
 
     if user_position.user == Pubkey::default() {
-        // Initialize new position
+        // Initialize new position, shares are added below
         user_position.initialize_user(
             ctx.accounts.user.key(),
             strategy.key(),
-            new_shares,
+            0,
             slot,
             ctx.bumps.user_position,
         );
-    } else {
-        // Calculate performance fee if share value has increased
-        performance_fee_shares = user_position.calculate_performance_fee_shares(
-            current_share_value,
-            ctx.accounts.global_config.performance_fee_bps,
-        )?;
-        msg!("Performance fee shares: {}", performance_fee_shares);
-
-        // Add fee shares to pending fees
-        if performance_fee_shares > 0 {
-            strategy.add_fee_shares(performance_fee_shares)?;
-        }
-
-        // Update existing position
-        user_position.update_after_deposit(
-            new_shares,
-            performance_fee_shares,
-            current_share_value,
-            slot,
-        )?;
     }
 
-    // Transfer tokens from user to strategy vault
-    token::transfer(
-        CpiContext::new(
+    // Account for share tokens transferred to or from the user
+    user_position.sync_share_balance(ctx.accounts.user_share_token.amount, current_share_value)?;
+
+    // Update position
+    user_position.update_after_deposit(new_shares, current_share_value, slot)?;
+
+    // Enforce the per user deposit cap on the resulting position value
+    let user_position_value =
+        strategy.calculate_withdrawal_amount(user_position.strategy_share, current_share_value)?;
+    strategy.validate_user_deposit_limit(user_position_value)?;
+
+    // Mint share tokens to the user
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.user_token_x.to_account_info(),
-                to: ctx.accounts.strategy_vault_x.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.user_share_token.to_account_info(),
+                authority: strategy.to_account_info(),
             },
+            &[&strategy.get_pda_signer()],
         ),
-        amount,
+        new_shares,
     )?;
 
     // Emit event
@@ -140,7 +230,7 @@ pub fn deposit_handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         user: ctx.accounts.user.key(),
         strategy: strategy.key(),
         shares_amount: new_shares,
-        token_amount: amount,
+        token_amount: received_amount,
         current_share_value,
         performance_fee_shares: performance_fee_shares,
         timestamp: clock.unix_timestamp,
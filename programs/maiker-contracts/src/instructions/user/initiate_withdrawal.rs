@@ -1,6 +1,9 @@
-use crate::{state::*, InitiateWithdrawEvent, MaikerError, ANCHOR_DISCRIMINATOR};
+use crate::{
+    math::u128x128_math::Rounding, state::*, validate, InitiateWithdrawEvent, MaikerError,
+    ANCHOR_DISCRIMINATOR,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct InitiateWithdrawal<'info> {
@@ -16,12 +19,13 @@ pub struct InitiateWithdrawal<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    // Holders that received share tokens may withdraw without having deposited
     #[account(
-        mut,
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + UserPosition::INIT_SPACE,
         seeds = [UserPosition::SEED_PREFIX.as_bytes(), user.key().as_ref(), strategy.key().as_ref()],
-        bump = user_position.bump,
-        constraint = user_position.user == user.key(),
-        constraint = user_position.strategy == strategy.key(),
+        bump
     )]
     pub user_position: Box<Account<'info, UserPosition>>,
 
@@ -38,14 +42,32 @@ pub struct InitiateWithdrawal<'info> {
         mut,
         constraint = strategy_vault_x.key() == strategy.x_vault
     )]
-    pub strategy_vault_x: Box<Account<'info, TokenAccount>>,
+    pub strategy_vault_x: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = strategy.share_mint,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_share_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn initiate_withdrawal_handler(
     ctx: Context<InitiateWithdrawal>,
     shares_amount: u64,
+    min_token_out: u64,
 ) -> Result<()> {
     let strategy = &mut ctx.accounts.strategy;
     let user_position = &mut ctx.accounts.user_position;
@@ -55,24 +77,39 @@ pub fn initiate_withdrawal_handler(
     let slot = clock.slot;
     let current_timestamp = clock.unix_timestamp;
 
-    // Validate that all positions have up-to-date values
-    strategy.validate_position_values_freshness(clock.slot)?;
+    // Validate that the cached strategy value is up-to-date
+    strategy.validate_strategy_value_freshness(clock.slot)?;
 
-    // Validate withdrawal amount
-    require!(
-        shares_amount > 0 && shares_amount <= user_position.strategy_share,
-        MaikerError::InvalidWithdrawalAmount
-    );
+    if user_position.user == Pubkey::default() {
+        user_position.initialize_user(
+            ctx.accounts.user.key(),
+            strategy.key(),
+            0,
+            slot,
+            ctx.bumps.user_position,
+        );
+    }
 
     // Calculate total strategy value and current share value
     let total_strategy_value =
         strategy.calculate_total_strategy_value(ctx.accounts.strategy_vault_x.amount)?;
 
-    let current_share_value = strategy.calculate_share_value(total_strategy_value)?;
+    // Charge the performance fee on the gain since the last charge, before pricing the withdrawal
+    let performance_fee_shares = strategy
+        .crystallize_performance_fee(total_strategy_value, global_config.performance_fee_bps)?;
+
+    // The share value is rounded down so the withdrawal amount is rounded down
+    let current_share_value =
+        strategy.calculate_share_value(total_strategy_value, Rounding::Down)?;
+
+    // Account for share tokens transferred to or from the user
+    user_position.sync_share_balance(ctx.accounts.user_share_token.amount, current_share_value)?;
 
-    // Calculate fees to withdraw
-    let performance_fee_shares = user_position
-        .calculate_performance_fee_shares(current_share_value, global_config.performance_fee_bps)?;
+    // Validate withdrawal amount
+    require!(
+        shares_amount > 0 && shares_amount <= user_position.strategy_share,
+        MaikerError::InvalidWithdrawalAmount
+    );
 
     // Calculate withdrawal fee as bps on the withdrawed shares
     let withdrawal_fee_shares = user_position
@@ -80,14 +117,21 @@ pub fn initiate_withdrawal_handler(
 
     let effective_shares_to_withdraw = shares_amount
         .checked_sub(withdrawal_fee_shares)
-        .ok_or(MaikerError::ArithmeticOverflow)?
-        .checked_sub(performance_fee_shares)
         .ok_or(MaikerError::ArithmeticOverflow)?;
 
     // Calculate token amount to return to user
     let token_amount =
         strategy.calculate_withdrawal_amount(effective_shares_to_withdraw, current_share_value)?;
 
+    // Protect the user against share value changes since signing
+    validate!(
+        token_amount >= min_token_out,
+        MaikerError::SlippageExceeded,
+        "Token out {} below minimum {}",
+        token_amount,
+        min_token_out
+    )?;
+
     // Calculate the next withdrawal window
     let available_timestamp = global_config.calculate_withdrawal_timestamp(current_timestamp)?;
 
@@ -102,16 +146,28 @@ pub fn initiate_withdrawal_handler(
         ctx.bumps.pending_withdrawal,
     );
 
+    strategy.add_pending_withdrawal()?;
+
     // 1. Reduce user position shares by shares_amount from input
-    user_position.update_after_withdrawal(shares_amount, current_share_value, slot)?;
-    // 2. Add performance fee shares to strategy fee shares to strategy config
-    let total_fee_shares = performance_fee_shares
-        .checked_add(withdrawal_fee_shares)
-        .ok_or(MaikerError::ArithmeticOverflow)?;
-    strategy.add_fee_shares(total_fee_shares)?;
+    user_position.update_after_withdrawal(shares_amount, slot)?;
+    // 2. Add withdrawal fee shares to strategy fee shares to strategy config
+    strategy.add_fee_shares(withdrawal_fee_shares)?;
+
+    // 3. Burn the withdrawn share tokens. The effective shares stay in the strategy supply until processed
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                from: ctx.accounts.user_share_token.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        shares_amount,
+    )?;
 
     // Removed as it creates issues with the share value calculation. Now instead we reduce the total strategy shares in the process_withdrawal instruction
-    // 3. Reduce total strategy shares by effective_shares_to_withdraw
+    // 4. Reduce total strategy shares by effective_shares_to_withdraw
     // strategy.burn_shares(effective_shares_to_withdraw)?;
 
     // Emit event for withdrawal initiation
@@ -1,11 +1,17 @@
+pub mod close_user_position;
 pub mod create_strategy;
 pub mod deposit;
 pub mod get_position_value;
 pub mod initiate_withdrawal;
 pub mod process_withdrawal;
+pub mod refresh_strategy_value;
+pub mod transfer_shares;
 
+pub use close_user_position::*;
 pub use create_strategy::*;
 pub use deposit::*;
 pub use get_position_value::*;
 pub use initiate_withdrawal::*;
 pub use process_withdrawal::*;
+pub use refresh_strategy_value::*;
+pub use transfer_shares::*;
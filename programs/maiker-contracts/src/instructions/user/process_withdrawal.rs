@@ -1,6 +1,6 @@
-use crate::{state::*, MaikerError, ProcessWithdrawEvent};
+use crate::{controllers, state::*, MaikerError, ProcessWithdrawEvent};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct ProcessWithdrawal<'info> {
@@ -30,17 +30,25 @@ pub struct ProcessWithdrawal<'info> {
         mut,
         token::mint = strategy.x_mint,
         token::authority = strategy.key(),
+        token::token_program = token_x_program,
     )]
-    pub strategy_vault_x: Box<Account<'info, TokenAccount>>,
+    pub strategy_vault_x: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = strategy.x_mint,
         token::authority = pending_withdrawal.user,
+        token::token_program = token_x_program,
     )]
-    pub user_token_x: Box<Account<'info, TokenAccount>>,
+    pub user_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        address = strategy.x_mint,
+        mint::token_program = token_x_program,
+    )]
+    pub x_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_x_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -59,27 +67,26 @@ pub fn process_withdrawal_handler(ctx: Context<ProcessWithdrawal>) -> Result<()>
     // Get the token amount and fee from the pending withdrawal
     let token_amount = pending_withdrawal.token_amount;
 
+    // Withdrawals are never blocked by a transfer fee, the withheld amount is disclosed instead
+    let transfer_fee =
+        controllers::token::get_transfer_fee(&ctx.accounts.x_mint.to_account_info(), token_amount)?;
+
     // Transfer tokens to user
     if token_amount > 0 {
-        let strategy_signer_seeds = strategy.get_pda_signer();
-        let signer = &[&strategy_signer_seeds[..]];
-
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.strategy_vault_x.to_account_info(),
-                    to: ctx.accounts.user_token_x.to_account_info(),
-                    authority: strategy.to_account_info(),
-                },
-                signer,
-            ),
+        controllers::token::send_from_program_vault(
+            &ctx.accounts.token_x_program,
+            &ctx.accounts.strategy_vault_x,
+            &ctx.accounts.user_token_x,
+            strategy,
             token_amount,
+            &ctx.accounts.x_mint,
+            true,
         )?;
     }
 
     // Reduce total strategy shares by effective_shares_to_withdraw
     strategy.burn_shares(pending_withdrawal.shares_amount)?;
+    strategy.remove_pending_withdrawal()?;
 
     // Emit event
     emit!(ProcessWithdrawEvent {
@@ -87,6 +94,7 @@ pub fn process_withdrawal_handler(ctx: Context<ProcessWithdrawal>) -> Result<()>
         strategy: strategy.key(),
         shares_amount: pending_withdrawal.shares_amount,
         token_amount: token_amount,
+        transfer_fee,
         timestamp: current_timestamp,
     });
 
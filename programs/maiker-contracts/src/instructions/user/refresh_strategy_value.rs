@@ -0,0 +1,70 @@
+use crate::controllers::valuation::calculate_position_value;
+use anchor_lang::prelude::*;
+
+use crate::{MaikerError, StrategyConfig, StrategyPosition};
+
+/// Accounts expected in `remaining_accounts` per position: strategy_position, position, lb_pair, bin_array_lower,
+/// bin_array_upper, oracle (the DLMM oracle of the lb_pair, or the strategy price feed)
+pub const REFRESH_ACCOUNTS_PER_POSITION: usize = 6;
+
+#[derive(Accounts)]
+pub struct RefreshStrategyValue<'info> {
+    #[account(mut)]
+    pub strategy: Account<'info, StrategyConfig>,
+
+    pub user: Signer<'info>,
+}
+
+/// Values every position of the strategy in one pass. Positions may be passed in any order,
+/// but every position of the strategy has to be passed for the cached total to be refreshed.
+pub fn refresh_strategy_value_handler<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RefreshStrategyValue<'info>>,
+) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let slot = Clock::get()?.slot;
+    let position_count = strategy.position_count as usize;
+
+    require!(
+        ctx.remaining_accounts.len() == position_count * REFRESH_ACCOUNTS_PER_POSITION,
+        MaikerError::InvalidRemainingAccounts
+    );
+
+    for accounts in ctx.remaining_accounts.chunks(REFRESH_ACCOUNTS_PER_POSITION) {
+        let [strategy_position_info, position, lb_pair, bin_array_lower, bin_array_upper, oracle] =
+            accounts
+        else {
+            return Err(MaikerError::InvalidRemainingAccounts.into());
+        };
+
+        require!(
+            strategy_position_info.is_writable,
+            MaikerError::InvalidRemainingAccounts
+        );
+        let mut strategy_position = Account::<StrategyPosition>::try_from(strategy_position_info)?;
+        require!(
+            strategy_position.strategy == strategy.key()
+                && strategy_position.position == position.key()
+                && strategy_position.lb_pair == lb_pair.key(),
+            MaikerError::InvalidPosition
+        );
+
+        let position_value = calculate_position_value(
+            strategy,
+            position,
+            lb_pair,
+            bin_array_lower,
+            bin_array_upper,
+            Some(oracle),
+        )?;
+
+        strategy.update_position_value(&mut strategy_position, position_value, slot)?;
+        strategy_position.exit(&crate::ID)?;
+    }
+
+    // A position passed twice is only counted once, so a missing position leaves the total stale
+    strategy.validate_strategy_value_freshness(slot)?;
+
+    msg!("Total positions value: {}", strategy.total_value);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::{
+    math::u128x128_math::Rounding, state::*, MaikerError, TransferSharesEvent, ANCHOR_DISCRIMINATOR,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct TransferShares<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Any wallet may receive shares
+    #[account(constraint = recipient.key() != user.key() @ MaikerError::InvalidShareTransfer)]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub strategy: Box<Account<'info, StrategyConfig>>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX.as_bytes()],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), user.key().as_ref(), strategy.key().as_ref()],
+        bump = user_position.bump,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + UserPosition::INIT_SPACE,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), recipient.key().as_ref(), strategy.key().as_ref()],
+        bump
+    )]
+    pub recipient_position: Box<Account<'info, UserPosition>>,
+
+    #[account(
+        constraint = strategy_vault_x.key() == strategy.x_vault
+    )]
+    pub strategy_vault_x: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = strategy.share_mint,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_share_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = share_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_share_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token program of the share mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_shares_handler(ctx: Context<TransferShares>, shares_amount: u64) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let user_position = &mut ctx.accounts.user_position;
+    let recipient_position = &mut ctx.accounts.recipient_position;
+    let clock = Clock::get()?;
+    let slot = clock.slot;
+
+    // Validate that the cached strategy value is up-to-date
+    strategy.validate_strategy_value_freshness(slot)?;
+
+    if recipient_position.user == Pubkey::default() {
+        recipient_position.initialize_user(
+            ctx.accounts.recipient.key(),
+            strategy.key(),
+            0,
+            slot,
+            ctx.bumps.recipient_position,
+        );
+    }
+
+    // Calculate total strategy value
+    let total_strategy_value =
+        strategy.calculate_total_strategy_value(ctx.accounts.strategy_vault_x.amount)?;
+
+    // Crystallize the performance fee first, so the gain on the transferred shares is charged before they move.
+    // The fee is charged on the strategy share value, so moving share tokens by any other means can't avoid it.
+    let performance_fee_shares = strategy.crystallize_performance_fee(
+        total_strategy_value,
+        ctx.accounts.global_config.performance_fee_bps,
+    )?;
+    msg!("Performance fee shares: {}", performance_fee_shares);
+
+    let current_share_value = strategy.calculate_share_value(total_strategy_value, Rounding::Up)?;
+
+    // Account for share tokens transferred to or from either user
+    user_position.sync_share_balance(ctx.accounts.user_share_token.amount, current_share_value)?;
+    recipient_position.sync_share_balance(
+        ctx.accounts.recipient_share_token.amount,
+        current_share_value,
+    )?;
+
+    // Validate transfer amount
+    require!(
+        shares_amount > 0 && shares_amount <= user_position.strategy_share,
+        MaikerError::InvalidShareTransfer
+    );
+
+    // Move the shares, the sender's share value is carried over to the recipient weighted by size
+    user_position.update_after_withdrawal(shares_amount, slot)?;
+    recipient_position.receive_shares(shares_amount, user_position.last_share_value)?;
+    recipient_position.last_update_slot = slot;
+
+    // Transfer the share tokens to the recipient
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.user_share_token.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.recipient_share_token.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        shares_amount,
+        ctx.accounts.share_mint.decimals,
+    )?;
+
+    // Emit event
+    emit!(TransferSharesEvent {
+        strategy: strategy.key(),
+        from: ctx.accounts.user.key(),
+        to: ctx.accounts.recipient.key(),
+        shares_amount,
+        current_share_value,
+        performance_fee_shares,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
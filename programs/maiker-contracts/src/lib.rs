@@ -33,26 +33,49 @@ pub mod maiker_contracts {
     }
 
     // User instructions
-    pub fn create_strategy(ctx: Context<CreateStrategy>) -> Result<()> {
-        instructions::create_strategy_handler(ctx)
+    pub fn create_strategy(ctx: Context<CreateStrategy>, strategy_id: u16) -> Result<()> {
+        instructions::create_strategy_handler(ctx, strategy_id)
     }
 
-    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-        instructions::deposit_handler(ctx, amount)
+    pub fn deposit(
+        ctx: Context<Deposit>,
+        amount: u64,
+        min_shares_out: u64,
+        merkle_proof: Option<Vec<[u8; 32]>>,
+    ) -> Result<()> {
+        instructions::deposit_handler(ctx, amount, min_shares_out, merkle_proof)
     }
 
-    pub fn initiate_withdrawal(ctx: Context<InitiateWithdrawal>, shares_amount: u64) -> Result<()> {
-        instructions::initiate_withdrawal_handler(ctx, shares_amount)
+    pub fn initiate_withdrawal(
+        ctx: Context<InitiateWithdrawal>,
+        shares_amount: u64,
+        min_token_out: u64,
+    ) -> Result<()> {
+        instructions::initiate_withdrawal_handler(ctx, shares_amount, min_token_out)
     }
 
     pub fn process_withdrawal(ctx: Context<ProcessWithdrawal>) -> Result<()> {
         instructions::process_withdrawal_handler(ctx)
     }
 
+    pub fn close_user_position(ctx: Context<CloseUserPosition>) -> Result<()> {
+        instructions::close_user_position_handler(ctx)
+    }
+
+    pub fn transfer_shares(ctx: Context<TransferShares>, shares_amount: u64) -> Result<()> {
+        instructions::transfer_shares_handler(ctx, shares_amount)
+    }
+
     pub fn get_position_value(ctx: Context<GetPositionValue>) -> Result<()> {
         instructions::get_position_value_handler(ctx)
     }
 
+    pub fn refresh_strategy_value<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RefreshStrategyValue<'info>>,
+    ) -> Result<()> {
+        instructions::refresh_strategy_value_handler(ctx)
+    }
+
     // Admin Instructions
     pub fn update_global_config(
         ctx: Context<UpdateGlobalConfig>,
@@ -61,11 +84,61 @@ pub mod maiker_contracts {
         instructions::update_global_config_handler(ctx, global_config_args)
     }
 
+    pub fn update_strategy_config(
+        ctx: Context<UpdateStrategyConfig>,
+        strategy_config_args: StrategyConfigArgs,
+    ) -> Result<()> {
+        instructions::update_strategy_config_handler(ctx, strategy_config_args)
+    }
+
+    // Callable by the strategy creator or the admin
+    pub fn update_strategy_metadata(
+        ctx: Context<UpdateStrategyMetadata>,
+        strategy_metadata_args: StrategyMetadataArgs,
+    ) -> Result<()> {
+        instructions::update_strategy_metadata_handler(ctx, strategy_metadata_args)
+    }
+
+    // Approves a creator to create and operate their own strategies
+    pub fn add_strategy_creator(ctx: Context<AddStrategyCreator>, creator: Pubkey) -> Result<()> {
+        instructions::add_strategy_creator_handler(ctx, creator)
+    }
+
+    // Revokes the approval to create new strategies
+    pub fn remove_strategy_creator(ctx: Context<RemoveStrategyCreator>) -> Result<()> {
+        instructions::remove_strategy_creator_handler(ctx)
+    }
+
+    pub fn add_depositor_allowance(
+        ctx: Context<AddDepositorAllowance>,
+        user: Pubkey,
+    ) -> Result<()> {
+        instructions::add_depositor_allowance_handler(ctx, user)
+    }
+
+    pub fn remove_depositor_allowance(ctx: Context<RemoveDepositorAllowance>) -> Result<()> {
+        instructions::remove_depositor_allowance_handler(ctx)
+    }
+
+    // Allows or disallows a Token-2022 mint with a non-zero transfer fee
+    pub fn update_transfer_fee_mint(
+        ctx: Context<UpdateTransferFeeMint>,
+        mint: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::update_transfer_fee_mint_handler(ctx, mint, allowed)
+    }
+
     // Claims the actual tokens to treasury wallet
     pub fn claim_fees(ctx: Context<ClaimFees>, shares_to_claim: Option<u64>) -> Result<()> {
         instructions::claim_fees_handler(ctx, shares_to_claim)
     }
 
+    // Sweeps the vault dust to the treasury and closes the strategy accounts
+    pub fn close_strategy(ctx: Context<CloseStrategy>) -> Result<()> {
+        instructions::close_strategy_handler(ctx)
+    }
+
     // TODO: Claim Performance Fee on User Position -> Only required for users that infrequently deposit/withdraw funds so we don't leak auto-compound fees
 
     // CPI instructions
@@ -76,10 +149,90 @@ pub mod maiker_contracts {
         instructions::add_liquidity_handler(ctx, liquidity_parameter)
     }
 
+    // Adds liquidity shaped by a DLMM strategy (spot, curve, bid-ask) from both vaults
+    pub fn add_liquidity_by_strategy(
+        ctx: Context<AddLiquidity>,
+        liquidity_parameter: LiquidityParameterByStrategy,
+    ) -> Result<()> {
+        instructions::add_liquidity_by_strategy_handler(ctx, liquidity_parameter)
+    }
+
+    // Adds liquidity shaped by a DLMM strategy from both vaults, supporting Token-2022 pairs
+    pub fn add_liquidity_by_strategy2(
+        ctx: Context<AddLiquidity>,
+        liquidity_parameter: LiquidityParameterByStrategy,
+    ) -> Result<()> {
+        instructions::add_liquidity_by_strategy2_handler(ctx, liquidity_parameter)
+    }
+
+    // Adds liquidity shaped by a DLMM strategy from the vault of one side
+    pub fn add_liquidity_by_strategy_one_side(
+        ctx: Context<AddLiquidity>,
+        side: LiquiditySide,
+        liquidity_parameter: LiquidityParameterByStrategyOneSide,
+    ) -> Result<()> {
+        instructions::add_liquidity_by_strategy_one_side_handler(ctx, side, liquidity_parameter)
+    }
+
+    // Adds liquidity distributed by weight from the vault of one side
+    pub fn add_liquidity_one_side(
+        ctx: Context<AddLiquidity>,
+        side: LiquiditySide,
+        liquidity_parameter: LiquidityOneSideParameter,
+    ) -> Result<()> {
+        instructions::add_liquidity_one_side_handler(ctx, side, liquidity_parameter)
+    }
+
+    // Adds exact amounts per bin from the vault of one side
+    pub fn add_liquidity_one_side_precise(
+        ctx: Context<AddLiquidity>,
+        side: LiquiditySide,
+        parameter: AddLiquiditySingleSidePreciseParameter,
+    ) -> Result<()> {
+        instructions::add_liquidity_one_side_precise_handler(ctx, side, parameter)
+    }
+
+    // Adds exact amounts per bin from the vault of one side, capped by a max amount
+    pub fn add_liquidity_one_side_precise2(
+        ctx: Context<AddLiquidity>,
+        side: LiquiditySide,
+        liquidity_parameter: AddLiquiditySingleSidePreciseParameter2,
+    ) -> Result<()> {
+        instructions::add_liquidity_one_side_precise2_handler(ctx, side, liquidity_parameter)
+    }
+
     pub fn remove_liquidity(ctx: Context<RemoveLiquidity>) -> Result<()> {
         instructions::remove_all_liquidity_handler(ctx)
     }
 
+    // Removes a share of the liquidity of a bin range of the position
+    pub fn remove_liquidity_by_range(
+        ctx: Context<RemoveLiquidity>,
+        from_bin_id: i32,
+        to_bin_id: i32,
+        bps_to_remove: u16,
+    ) -> Result<()> {
+        instructions::remove_liquidity_by_range_handler(ctx, from_bin_id, to_bin_id, bps_to_remove)
+    }
+
+    // Removes a share of the liquidity of a bin range of the position, supporting Token-2022 pairs
+    pub fn remove_liquidity_by_range2(
+        ctx: Context<RemoveLiquidity>,
+        from_bin_id: i32,
+        to_bin_id: i32,
+        bps_to_remove: u16,
+    ) -> Result<()> {
+        instructions::remove_liquidity_by_range2_handler(ctx, from_bin_id, to_bin_id, bps_to_remove)
+    }
+
+    // Removes a share of the liquidity of each listed bin of the position
+    pub fn remove_liquidity_by_bins(
+        ctx: Context<RemoveLiquidity>,
+        bin_liquidity_removal: Vec<BinLiquidityReduction>,
+    ) -> Result<()> {
+        instructions::remove_liquidity_by_bins_handler(ctx, bin_liquidity_removal)
+    }
+
     pub fn claim_fee(ctx: Context<ClaimFee>) -> Result<()> {
         instructions::claim_fee_handler(ctx)
     }
@@ -96,6 +249,15 @@ pub mod maiker_contracts {
         instructions::initialize_position_handler(ctx, lower_bin_id, width)
     }
 
+    // Initializes a DLMM position PDA derived from the strategy, lb_pair, lower bin id and width
+    pub fn initialize_position_pda(
+        ctx: Context<InitializePositionPda>,
+        lower_bin_id: i32,
+        width: i32,
+    ) -> Result<()> {
+        instructions::initialize_position_pda_handler(ctx, lower_bin_id, width)
+    }
+
     pub fn swap_exact_in<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
         amount_in: u64,
@@ -112,4 +274,23 @@ pub mod maiker_contracts {
     pub fn end_swap(ctx: Context<FlashSwap>, x_to_y: bool) -> Result<()> {
         instructions::end_swap_handler(ctx, x_to_y)
     }
+
+    // Migrations
+    pub fn migrate_global_config(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+        instructions::migrate_global_config_handler(ctx)
+    }
+
+    pub fn migrate_strategy_config<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, MigrateStrategyConfig<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_strategy_config_handler(ctx)
+    }
+
+    pub fn migrate_user_position(ctx: Context<MigrateUserPosition>) -> Result<()> {
+        instructions::migrate_user_position_handler(ctx)
+    }
+
+    pub fn migrate_pending_withdrawal(ctx: Context<MigratePendingWithdrawal>) -> Result<()> {
+        instructions::migrate_pending_withdrawal_handler(ctx)
+    }
 }
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct DepositorAllowance {
+    pub version: u8,        // Layout version, see migrate_* instructions
+    pub strategy: Pubkey,   // Strategy the user is allowed to deposit into
+    pub user: Pubkey,       // Allowed depositor
+    pub bump: u8,           // PDA bump
+    pub reserved: [u8; 32], // Space for fields added in later versions
+}
+
+impl DepositorAllowance {
+    pub const SEED_PREFIX: &'static str = "depositor-allowance";
+    pub const VERSION: u8 = 1;
+
+    pub fn initialize(&mut self, strategy: Pubkey, user: Pubkey, bump: u8) {
+        self.version = Self::VERSION;
+        self.strategy = strategy;
+        self.user = user;
+        self.bump = bump;
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::MaikerError;
+use crate::{MaikerError, MAX_TRANSFER_FEE_MINTS};
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize)]
 pub struct GlobalConfigArgs {
@@ -14,16 +14,21 @@ pub struct GlobalConfigArgs {
 #[account]
 #[derive(InitSpace)]
 pub struct GlobalConfig {
+    pub version: u8,                      // Layout version, see migrate_* instructions
     pub admin: Pubkey,                    // Primary admin with full control
     pub performance_fee_bps: u16,         // Performance fee in basis points
     pub withdrawal_fee_bps: u16,          // Optional withdrawal fee in basis points
     pub treasury: Pubkey,                 // Address where fees are sent
     pub withdrawal_interval_seconds: u64, // Time interval for withdrawal windows (default: 3600 = 1 hour)
-    pub bump: u8,                         // PDA bump
+    pub transfer_fee_mints: [Pubkey; MAX_TRANSFER_FEE_MINTS], // Token-2022 mints accepted with a non-zero transfer fee
+    pub strategy_count: u64, // Number of strategies registered, the next strategy index
+    pub bump: u8,            // PDA bump
+    pub reserved: [u8; 128], // Space for fields added in later versions
 }
 
 impl GlobalConfig {
     pub const SEED_PREFIX: &'static str = "global-config";
+    pub const VERSION: u8 = 1;
 
     pub fn get_pda_signer<'a>(self: &'a Self) -> [&'a [u8]; 2] {
         let prefix_bytes = Self::SEED_PREFIX.as_bytes();
@@ -59,11 +64,14 @@ impl GlobalConfig {
     }
 
     pub fn initialize_global_config(&mut self, args: GlobalConfigArgs, admin: Pubkey, bump: u8) {
+        self.version = Self::VERSION;
         self.admin = admin;
         self.performance_fee_bps = args.performance_fee_bps;
         self.withdrawal_fee_bps = args.withdrawal_fee_bps;
         self.treasury = args.treasury;
         self.withdrawal_interval_seconds = args.interval_seconds;
+        self.transfer_fee_mints = [Pubkey::default(); MAX_TRANSFER_FEE_MINTS];
+        self.strategy_count = 0;
         self.bump = bump;
     }
 
@@ -74,4 +82,40 @@ impl GlobalConfig {
         self.withdrawal_interval_seconds = args.interval_seconds;
         self.admin = args.new_admin.unwrap_or(self.admin);
     }
+
+    /// Registers a new strategy. Returns the index of the strategy
+    pub fn add_strategy(&mut self) -> Result<u64> {
+        let strategy_index = self.strategy_count;
+        self.strategy_count = self
+            .strategy_count
+            .checked_add(1)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+
+        Ok(strategy_index)
+    }
+
+    pub fn is_transfer_fee_mint_allowed(&self, mint: &Pubkey) -> bool {
+        *mint != Pubkey::default() && self.transfer_fee_mints.contains(mint)
+    }
+
+    /// Add or remove a mint from the transfer fee allowlist
+    pub fn set_transfer_fee_mint(&mut self, mint: Pubkey, allowed: bool) -> Result<()> {
+        require!(mint != Pubkey::default(), MaikerError::InvalidMint);
+
+        if allowed {
+            if self.is_transfer_fee_mint_allowed(&mint) {
+                return Ok(());
+            }
+            let slot = self
+                .transfer_fee_mints
+                .iter_mut()
+                .find(|m| **m == Pubkey::default())
+                .ok_or(MaikerError::MaxTransferFeeMintsReached)?;
+            *slot = mint;
+        } else if let Some(slot) = self.transfer_fee_mints.iter_mut().find(|m| **m == mint) {
+            *slot = Pubkey::default();
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DepositAccessMode, GlobalConfig, PendingWithdrawal, PriceSource, StrategyConfig,
+    StrategyPosition, UserPosition, MAX_TRANSFER_FEE_MINTS, SHARE_PRECISION,
+};
+
+// Layouts deployed before accounts carried a version. They share the discriminator of the current
+// account and are told apart by their size.
+
+/// Positions tracked inline by a StrategyConfigV0
+pub const LEGACY_MAX_POSITIONS: usize = 10;
+
+/// Fixed-point scale of the share value of UserPositionV0
+pub const LEGACY_SHARE_PRECISION: u128 = 1_000_000;
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct GlobalConfigV0 {
+    pub admin: Pubkey,
+    pub performance_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub treasury: Pubkey,
+    pub withdrawal_interval_seconds: u64,
+    pub bump: u8,
+}
+
+impl From<GlobalConfigV0> for GlobalConfig {
+    fn from(legacy: GlobalConfigV0) -> Self {
+        Self {
+            version: Self::VERSION,
+            admin: legacy.admin,
+            performance_fee_bps: legacy.performance_fee_bps,
+            withdrawal_fee_bps: legacy.withdrawal_fee_bps,
+            treasury: legacy.treasury,
+            withdrawal_interval_seconds: legacy.withdrawal_interval_seconds,
+            transfer_fee_mints: [Pubkey::default(); MAX_TRANSFER_FEE_MINTS],
+            // Legacy strategies are registered as they are migrated
+            strategy_count: 0,
+            bump: legacy.bump,
+            reserved: [0; 128],
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct StrategyConfigV0 {
+    pub creator: Pubkey,
+    pub x_mint: Pubkey,
+    pub y_mint: Pubkey,
+    pub x_vault: Pubkey,
+    pub y_vault: Pubkey,
+    pub strategy_shares: u64,
+    pub fee_shares: u64,
+    pub position_count: u8,
+    pub positions: [Pubkey; LEGACY_MAX_POSITIONS],
+    pub positions_values: [u64; LEGACY_MAX_POSITIONS],
+    pub last_position_update: [u64; LEGACY_MAX_POSITIONS],
+    pub last_rebalance_time: i64,
+    pub is_swapping: bool,
+    pub swap_amount_in: u64,
+    pub swap_source_mint: Pubkey,
+    pub swap_destination_mint: Pubkey,
+    pub swap_initial_in_amount_admin: u64,
+    pub swap_initial_out_amount_admin: u64,
+    pub bump: u8,
+}
+
+/// Position tracked inline by a StrategyConfigV0, moved to its own StrategyPosition PDA on migration
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LegacyPosition {
+    pub position: Pubkey,
+    pub value: u64,
+    pub value_slot: u64,
+}
+
+impl LegacyPosition {
+    /// StrategyPosition tracking the position, carrying over its last value
+    pub fn to_strategy_position(
+        &self,
+        strategy: Pubkey,
+        lb_pair: Pubkey,
+        lower_bin_id: i32,
+        upper_bin_id: i32,
+        bump: u8,
+    ) -> StrategyPosition {
+        StrategyPosition {
+            version: StrategyPosition::VERSION,
+            strategy,
+            position: self.position,
+            lb_pair,
+            lower_bin_id,
+            upper_bin_id,
+            value: self.value,
+            value_slot: self.value_slot,
+            valuation_round: 0,
+            cost_basis_x: 0,
+            cost_basis_y: 0,
+            accrued_fee_x: 0,
+            accrued_fee_y: 0,
+            bump,
+            // Legacy positions were created from keypairs
+            derived: false,
+            position_bump: 0,
+            reserved: [0; 62],
+        }
+    }
+}
+
+impl StrategyConfigV0 {
+    /// Positions are kept at the start of the arrays, the remaining slots are empty
+    pub fn tracked_positions(&self) -> Vec<LegacyPosition> {
+        let position_count = usize::from(self.position_count).min(LEGACY_MAX_POSITIONS);
+
+        (0..position_count)
+            .map(|i| LegacyPosition {
+                position: self.positions[i],
+                value: self.positions_values[i],
+                value_slot: self.last_position_update[i],
+            })
+            .collect()
+    }
+}
+
+impl From<StrategyConfigV0> for StrategyConfig {
+    fn from(legacy: StrategyConfigV0) -> Self {
+        let positions = legacy.tracked_positions();
+        let total_value = positions
+            .iter()
+            .fold(0u64, |total, position| total.saturating_add(position.value));
+        let value_slot = positions
+            .iter()
+            .map(|position| position.value_slot)
+            .min()
+            .unwrap_or(0);
+
+        Self {
+            version: Self::VERSION,
+            creator: legacy.creator,
+            x_mint: legacy.x_mint,
+            y_mint: legacy.y_mint,
+            // Set by migrate_strategy_config, which registers the strategy and creates its share mint
+            strategy_id: 0,
+            strategy_index: 0,
+            x_vault: legacy.x_vault,
+            y_vault: legacy.y_vault,
+            x_decimals: 0,
+            y_decimals: 0,
+            strategy_shares: legacy.strategy_shares,
+            share_mint: Pubkey::default(),
+            fee_shares: legacy.fee_shares,
+            position_count: positions.len() as u32,
+            valuation_round: 1,
+            valuation_slot: 0,
+            valued_position_count: 0,
+            valued_positions_value: 0,
+            total_value,
+            value_slot,
+            last_rebalance_time: legacy.last_rebalance_time,
+            last_rebalance_slot: 0,
+            is_swapping: legacy.is_swapping,
+            swap_amount_in: legacy.swap_amount_in,
+            swap_source_mint: legacy.swap_source_mint,
+            swap_destination_mint: legacy.swap_destination_mint,
+            swap_initial_in_amount_admin: legacy.swap_initial_in_amount_admin,
+            swap_initial_out_amount_admin: legacy.swap_initial_out_amount_admin,
+            twap_window_seconds: 0,
+            max_twap_deviation_bps: 0,
+            price_source: PriceSource::DlmmActiveBin,
+            price_feed: Pubkey::default(),
+            max_price_age_seconds: 0,
+            max_price_confidence_bps: 0,
+            max_total_deposits: 0,
+            max_deposit_per_user: 0,
+            min_deposit_amount: 0,
+            deposit_access_mode: DepositAccessMode::Open,
+            deposit_merkle_root: [0; 32],
+            pending_withdrawal_count: 0,
+            bump: legacy.bump,
+            max_liquidity_bin_width: 0,
+            max_active_bin_distance: 0,
+            // The legacy PDA was derived without the strategy_id seed
+            legacy_seeds: true,
+            // Legacy positions were charged on their own share value, the mark is set at the first charge
+            high_water_mark: 0,
+            reserved: [0; 103],
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct UserPositionV0 {
+    pub user: Pubkey,
+    pub strategy: Pubkey,
+    pub strategy_share: u64,
+    pub last_share_value: u64,
+    pub last_update_slot: u64,
+    pub bump: u8,
+}
+
+impl From<UserPositionV0> for UserPosition {
+    fn from(legacy: UserPositionV0) -> Self {
+        Self {
+            version: Self::VERSION,
+            user: legacy.user,
+            strategy: legacy.strategy,
+            strategy_share: legacy.strategy_share,
+            // Rescaled to the current fixed-point precision of the share value
+            last_share_value: u128::from(legacy.last_share_value)
+                * (SHARE_PRECISION / LEGACY_SHARE_PRECISION),
+            last_update_slot: legacy.last_update_slot,
+            bump: legacy.bump,
+            reserved: [0; 64],
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct PendingWithdrawalV0 {
+    pub user: Pubkey,
+    pub strategy: Pubkey,
+    pub shares_amount: u64,
+    pub token_amount: u64,
+    pub initiation_timestamp: i64,
+    pub available_timestamp: i64,
+    pub bump: u8,
+}
+
+impl From<PendingWithdrawalV0> for PendingWithdrawal {
+    fn from(legacy: PendingWithdrawalV0) -> Self {
+        Self {
+            version: Self::VERSION,
+            user: legacy.user,
+            strategy: legacy.strategy,
+            shares_amount: legacy.shares_amount,
+            token_amount: legacy.token_amount,
+            initiation_timestamp: legacy.initiation_timestamp,
+            available_timestamp: legacy.available_timestamp,
+            bump: legacy.bump,
+            reserved: [0; 64],
+        }
+    }
+}
@@ -1,9 +1,21 @@
+pub mod depositor_allowance;
 pub mod global_config;
+pub mod legacy;
 pub mod pending_withdrawal;
 pub mod strategy_config;
+pub mod strategy_creator;
+pub mod strategy_metadata;
+pub mod strategy_position;
+pub mod strategy_registry_entry;
 pub mod user_position;
 
+pub use depositor_allowance::*;
 pub use global_config::*;
+pub use legacy::*;
 pub use pending_withdrawal::*;
 pub use strategy_config::*;
+pub use strategy_creator::*;
+pub use strategy_metadata::*;
+pub use strategy_position::*;
+pub use strategy_registry_entry::*;
 pub use user_position::*;
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct PendingWithdrawal {
+    pub version: u8,               // Layout version, see migrate_* instructions
     pub user: Pubkey,              // User who initiated the withdrawal
     pub strategy: Pubkey,          // Strategy from which to withdraw
     pub shares_amount: u64,        // Amount of shares to withdraw
@@ -10,10 +11,12 @@ pub struct PendingWithdrawal {
     pub initiation_timestamp: i64, // When the withdrawal was initiated
     pub available_timestamp: i64,  // When the withdrawal becomes available
     pub bump: u8,                  // PDA bump
+    pub reserved: [u8; 64],        // Space for fields added in later versions
 }
 
 impl PendingWithdrawal {
     pub const SEED_PREFIX: &'static str = "pending-withdrawal";
+    pub const VERSION: u8 = 1;
 
     pub fn get_pda_signer<'a>(self: &'a Self) -> [&'a [u8]; 4] {
         let prefix_bytes = Self::SEED_PREFIX.as_bytes();
@@ -33,6 +36,7 @@ impl PendingWithdrawal {
         available_timestamp: i64,
         bump: u8,
     ) {
+        self.version = Self::VERSION;
         self.user = user;
         self.strategy = strategy;
         self.shares_amount = shares_amount;
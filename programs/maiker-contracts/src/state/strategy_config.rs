@@ -1,30 +1,89 @@
-use crate::{validate, MaikerError, MAX_POSITIONS, SHARE_PRECISION};
+use crate::{
+    math::{u128x128_math::Rounding, utils_math::safe_mul_div_cast},
+    validate, GlobalConfig, MaikerError, StrategyCreator, StrategyPosition, BASIS_POINT_MAX,
+    DEAD_SHARES, SHARE_PRECISION,
+};
 use anchor_lang::prelude::*;
+use ruint::aliases::U256;
+
+/// Source of the price used to value positions
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    /// Active bin of the position's lb_pair, optionally checked against the oracle TWAP
+    DlmmActiveBin,
+    /// TWAP of the active bin from the lb_pair's oracle
+    DlmmTwap,
+    /// Pyth price account quoting the lb_pair's token X in token Y
+    Pyth,
+}
+
+/// Who may deposit into a strategy
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositAccessMode {
+    /// Anyone may deposit
+    Open,
+    /// Users with a DepositorAllowance PDA may deposit
+    Allowlist,
+    /// Users with a Merkle proof against deposit_merkle_root may deposit
+    MerkleRoot,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct StrategyConfigArgs {
+    pub twap_window_seconds: u64,
+    pub max_twap_deviation_bps: u16,
+    pub price_source: PriceSource,
+    pub price_feed: Pubkey,
+    pub max_price_age_seconds: u64,
+    pub max_price_confidence_bps: u16,
+    pub max_total_deposits: u64,
+    pub max_deposit_per_user: u64,
+    pub min_deposit_amount: u64,
+    pub deposit_access_mode: DepositAccessMode,
+    pub deposit_merkle_root: [u8; 32],
+    pub max_liquidity_bin_width: u32,
+    pub max_active_bin_distance: u32,
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct StrategyConfig {
+    pub version: u8, // Layout version, see migrate_* instructions
     pub creator: Pubkey,
     pub x_mint: Pubkey,
     pub y_mint: Pubkey,
+    pub strategy_id: u16, // Distinguishes strategies of a creator on the same pair
+    pub strategy_index: u64, // Index in the global strategy registry, see StrategyRegistryEntry
     pub x_vault: Pubkey,
     pub y_vault: Pubkey,
+    pub x_decimals: u8,
+    pub y_decimals: u8,
 
     // Total shares issued
     pub strategy_shares: u64,
 
+    // Mint of the share tokens held by users. Fee shares and shares of pending withdrawals are not minted
+    pub share_mint: Pubkey,
+
     // Fee Shares
     pub fee_shares: u64,
 
-    // Direct position tracking
-    // Potentially later require PDA per position to track position value accurately
-    pub position_count: u8,
-    pub positions: [Pubkey; MAX_POSITIONS],
-    pub positions_values: [u64; MAX_POSITIONS], // Total position value in token X
-    pub last_position_update: [u64; MAX_POSITIONS], // Slot of last position update
+    // Every position is tracked by its own StrategyPosition PDA
+    pub position_count: u32,
+
+    // Valuation round in progress, every position has to be valued once per round
+    pub valuation_round: u64,
+    pub valuation_slot: u64, // Slot in which the valuation round started
+    pub valued_position_count: u32, // Positions valued in the current round
+    pub valued_positions_value: u64, // Sum of the position values of the current round in token X
+
+    // Cached aggregate of all position values
+    pub total_value: u64, // Sum of the position values in token X
+    pub value_slot: u64,  // Slot in which every position value was last fresh
 
     // Rebalancing info
     pub last_rebalance_time: i64,
+    pub last_rebalance_slot: u64, // Slot of the last admin CPI touching strategy funds
 
     // Swap state
     pub is_swapping: bool,                  // Flag indicating an active swap
@@ -34,24 +93,68 @@ pub struct StrategyConfig {
     pub swap_initial_in_amount_admin: u64,  // Amount of the input token provided in begin_swap
     pub swap_initial_out_amount_admin: u64, // Amount of the output token provided in begin_swap
 
+    // Oracle guard
+    pub twap_window_seconds: u64, // Window of the DLMM oracle TWAP used to validate the active bin
+    pub max_twap_deviation_bps: u16, // Max deviation of the active bin from the TWAP, 0 disables the guard
+
+    // Price source
+    pub price_source: PriceSource,     // Price used to value positions
+    pub price_feed: Pubkey,            // External price account when price_source is Pyth
+    pub max_price_age_seconds: u64,    // Max age of the external price
+    pub max_price_confidence_bps: u16, // Max confidence interval of the external price
+
+    // Deposit limits, 0 disables a limit
+    pub max_total_deposits: u64, // Max total strategy value in token X after a deposit
+    pub max_deposit_per_user: u64, // Max value of a user position in token X after a deposit
+    pub min_deposit_amount: u64, // Min amount of token X credited by a deposit
+
+    // Deposit access
+    pub deposit_access_mode: DepositAccessMode, // Who may deposit
+    pub deposit_merkle_root: [u8; 32], // Root of the depositor allowlist tree in MerkleRoot mode
+
+    // Withdrawals initiated but not yet processed
+    pub pending_withdrawal_count: u64,
+
     // For PDA derivation
     pub bump: u8,
+
+    // Liquidity limits, 0 disables a limit. Taken from the reserved space, zero in older accounts
+    pub max_liquidity_bin_width: u32, // Max bins covered by a strategy shaped liquidity add
+    pub max_active_bin_distance: u32, // Max distance of an added bin from the active bin
+
+    // PDA derived without the strategy_id seed, set for strategies migrated from the first layout
+    pub legacy_seeds: bool,
+
+    // Performance fee, charged on the strategy share value so it does not depend on who holds the shares
+    pub high_water_mark: u128, // Share value the performance fee was last charged up to, 0 until the first charge
+
+    pub reserved: [u8; 103], // Space for fields added in later versions
 }
 
 impl StrategyConfig {
     pub const SEED_PREFIX: &'static str = "strategy-config";
+    pub const VERSION: u8 = 1;
+    pub const SHARE_MINT_SEED_PREFIX: &'static str = "share-mint";
 
-    pub fn get_pda_signer<'a>(self: &'a Self) -> [&'a [u8]; 5] {
+    pub fn get_pda_signer<'a>(self: &'a Self) -> [&'a [u8]; 6] {
         let prefix_bytes = Self::SEED_PREFIX.as_bytes();
         let creator_bytes = self.creator.as_ref();
         let x_mint_bytes = self.x_mint.as_ref();
         let y_mint_bytes = self.y_mint.as_ref();
+        // Little endian on Solana, matching strategy_id.to_le_bytes() in the account seeds.
+        // An empty seed adds nothing to the derivation, which gives the address of a legacy strategy.
+        let strategy_id_bytes: &'a [u8] = if self.legacy_seeds {
+            &[]
+        } else {
+            bytemuck::bytes_of(&self.strategy_id)
+        };
         let bump_slice: &'a [u8] = std::slice::from_ref(&self.bump);
         [
             prefix_bytes,
             creator_bytes,
             x_mint_bytes,
             y_mint_bytes,
+            strategy_id_bytes,
             bump_slice,
         ]
     }
@@ -65,6 +168,7 @@ impl StrategyConfig {
         y_vault: Pubkey,
         bump: u8,
     ) {
+        self.version = Self::VERSION;
         self.creator = owner;
         self.x_mint = x_mint;
         self.y_mint = y_mint;
@@ -73,17 +177,214 @@ impl StrategyConfig {
         self.strategy_shares = 0;
         self.fee_shares = 0;
         self.position_count = 0;
-        self.positions = [Pubkey::default(); MAX_POSITIONS];
-        self.positions_values = [0; MAX_POSITIONS];
-        self.last_position_update = [0; MAX_POSITIONS];
+        // Round 0 marks positions that were never valued
+        self.valuation_round = 1;
+        self.valuation_slot = 0;
+        self.valued_position_count = 0;
+        self.valued_positions_value = 0;
+        self.total_value = 0;
+        self.value_slot = 0;
         self.last_rebalance_time = 0;
+        self.last_rebalance_slot = 0;
         self.is_swapping = false; // Initialize swap state
         self.swap_amount_in = 0;
         self.swap_source_mint = Pubkey::default();
         self.swap_destination_mint = Pubkey::default();
         self.swap_initial_in_amount_admin = 0;
         self.swap_initial_out_amount_admin = 0;
+        self.twap_window_seconds = 0;
+        self.max_twap_deviation_bps = 0;
+        self.price_source = PriceSource::DlmmActiveBin;
+        self.price_feed = Pubkey::default();
+        self.max_price_age_seconds = 0;
+        self.max_price_confidence_bps = 0;
+        self.max_total_deposits = 0;
+        self.max_deposit_per_user = 0;
+        self.min_deposit_amount = 0;
+        self.deposit_access_mode = DepositAccessMode::Open;
+        self.deposit_merkle_root = [0; 32];
+        self.pending_withdrawal_count = 0;
         self.bump = bump;
+        self.max_liquidity_bin_width = 0;
+        self.max_active_bin_distance = 0;
+        self.legacy_seeds = false;
+        self.high_water_mark = SHARE_PRECISION;
+    }
+
+    pub fn set_strategy_id(&mut self, strategy_id: u16, strategy_index: u64) {
+        self.strategy_id = strategy_id;
+        self.strategy_index = strategy_index;
+    }
+
+    /// The admin operates every strategy, creators operate their own strategies while they are approved.
+    /// `strategy_creator` is the registry entry of the strategy creator, if it still exists.
+    pub fn is_operator(
+        &self,
+        authority: Pubkey,
+        global_config: &GlobalConfig,
+        strategy_creator: Option<&StrategyCreator>,
+    ) -> bool {
+        if authority == global_config.admin {
+            return true;
+        }
+
+        match strategy_creator {
+            Some(strategy_creator) => {
+                authority == self.creator && strategy_creator.creator == self.creator
+            }
+            None => false,
+        }
+    }
+
+    pub fn set_token_decimals(&mut self, x_decimals: u8, y_decimals: u8) {
+        self.x_decimals = x_decimals;
+        self.y_decimals = y_decimals;
+    }
+
+    pub fn set_share_mint(&mut self, share_mint: Pubkey) {
+        self.share_mint = share_mint;
+    }
+
+    pub fn update_strategy_config(&mut self, args: StrategyConfigArgs) -> Result<()> {
+        validate!(
+            args.max_twap_deviation_bps <= BASIS_POINT_MAX as u16,
+            MaikerError::InvalidStrategyConfig,
+            "TWAP deviation exceeds 100%"
+        )?;
+        validate!(
+            (args.max_twap_deviation_bps == 0 && args.price_source != PriceSource::DlmmTwap)
+                || args.twap_window_seconds > 0,
+            MaikerError::InvalidStrategyConfig,
+            "TWAP window must be set when the oracle is used"
+        )?;
+        validate!(
+            args.price_source != PriceSource::Pyth
+                || (args.price_feed != Pubkey::default() && args.max_price_age_seconds > 0),
+            MaikerError::InvalidStrategyConfig,
+            "Price feed and max price age must be set for external prices"
+        )?;
+        validate!(
+            args.max_price_confidence_bps <= BASIS_POINT_MAX as u16,
+            MaikerError::InvalidStrategyConfig,
+            "Price confidence exceeds 100%"
+        )?;
+        validate!(
+            args.deposit_access_mode != DepositAccessMode::MerkleRoot
+                || args.deposit_merkle_root != [0; 32],
+            MaikerError::InvalidStrategyConfig,
+            "Merkle root must be set for Merkle allowlisted deposits"
+        )?;
+
+        self.twap_window_seconds = args.twap_window_seconds;
+        self.max_twap_deviation_bps = args.max_twap_deviation_bps;
+        self.price_source = args.price_source;
+        self.price_feed = args.price_feed;
+        self.max_price_age_seconds = args.max_price_age_seconds;
+        self.max_price_confidence_bps = args.max_price_confidence_bps;
+        self.max_total_deposits = args.max_total_deposits;
+        self.max_deposit_per_user = args.max_deposit_per_user;
+        self.min_deposit_amount = args.min_deposit_amount;
+        self.deposit_access_mode = args.deposit_access_mode;
+        self.deposit_merkle_root = args.deposit_merkle_root;
+        self.max_liquidity_bin_width = args.max_liquidity_bin_width;
+        self.max_active_bin_distance = args.max_active_bin_distance;
+
+        Ok(())
+    }
+
+    /// Validates the bin range of a liquidity add against the strategy liquidity limits
+    pub fn validate_liquidity_bins(
+        &self,
+        min_bin_id: i32,
+        max_bin_id: i32,
+        active_id: i32,
+    ) -> Result<()> {
+        validate!(
+            min_bin_id <= max_bin_id,
+            MaikerError::InvalidBinId,
+            "Min bin {} is above max bin {}",
+            min_bin_id,
+            max_bin_id
+        )?;
+
+        if self.max_liquidity_bin_width > 0 {
+            let width = i64::from(max_bin_id) - i64::from(min_bin_id) + 1;
+            validate!(
+                width <= i64::from(self.max_liquidity_bin_width),
+                MaikerError::LiquidityLimitExceeded,
+                "Bin width {} exceeds the limit of {}",
+                width,
+                self.max_liquidity_bin_width
+            )?;
+        }
+
+        if self.max_active_bin_distance > 0 {
+            let distance = (i64::from(min_bin_id) - i64::from(active_id))
+                .abs()
+                .max((i64::from(max_bin_id) - i64::from(active_id)).abs());
+            validate!(
+                distance <= i64::from(self.max_active_bin_distance),
+                MaikerError::LiquidityLimitExceeded,
+                "Bins {} to {} are {} bins away from the active bin {}, the limit is {}",
+                min_bin_id,
+                max_bin_id,
+                distance,
+                active_id,
+                self.max_active_bin_distance
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates a deposit against the minimum deposit amount and the strategy deposit cap
+    pub fn validate_deposit_limits(
+        &self,
+        deposit_amount: u64,
+        total_strategy_value: u64,
+    ) -> Result<()> {
+        validate!(
+            deposit_amount >= self.min_deposit_amount,
+            MaikerError::DepositBelowMinimum,
+            "Deposit of {} is below the minimum of {}",
+            deposit_amount,
+            self.min_deposit_amount
+        )?;
+
+        if self.max_total_deposits > 0 {
+            let total_value_after_deposit = total_strategy_value
+                .checked_add(deposit_amount)
+                .ok_or(MaikerError::ArithmeticOverflow)?;
+            validate!(
+                total_value_after_deposit <= self.max_total_deposits,
+                MaikerError::StrategyDepositCapExceeded,
+                "Strategy value {} exceeds the cap of {}",
+                total_value_after_deposit,
+                self.max_total_deposits
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates the value of a user position after a deposit against the per user cap
+    pub fn validate_user_deposit_limit(&self, user_position_value: u64) -> Result<()> {
+        if self.max_deposit_per_user > 0 {
+            validate!(
+                user_position_value <= self.max_deposit_per_user,
+                MaikerError::UserDepositCapExceeded,
+                "User position value {} exceeds the cap of {}",
+                user_position_value,
+                self.max_deposit_per_user
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether position valuation needs an oracle account (DLMM oracle or external price feed)
+    pub fn requires_oracle(&self) -> bool {
+        self.price_source != PriceSource::DlmmActiveBin || self.max_twap_deviation_bps > 0
     }
 
     pub fn mint_shares(&mut self, amount: u64) -> Result<()> {
@@ -122,106 +423,239 @@ impl StrategyConfig {
         Ok(())
     }
 
-    /// Adds a new position to the strategy
-    /// Returns an error if the maximum number of positions has been reached
-    pub fn add_position(&mut self, position: Pubkey) -> Result<()> {
-        // Check if we have space for a new position
-        if self.position_count as usize >= self.positions.len() {
-            return Err(error!(MaikerError::MaxPositionsReached));
+    /// Charges the performance fee on the share value gained above the high-water mark.
+    /// The fee is paid by minting fee shares, which dilutes every holder by the same share of their gain,
+    /// and the high-water mark moves to the share value after the fee. Returns the minted fee shares.
+    pub fn crystallize_performance_fee(
+        &mut self,
+        total_strategy_value: u64,
+        performance_fee_bps: u16,
+    ) -> Result<u64> {
+        if self.strategy_shares == 0 {
+            self.high_water_mark = SHARE_PRECISION;
+            return Ok(0);
+        }
+
+        let share_value = self.calculate_share_value(total_strategy_value, Rounding::Down)?;
+
+        // Strategies migrated from the first layout start charging from their first valuation
+        if self.high_water_mark == 0 {
+            self.high_water_mark = share_value;
+            return Ok(0);
+        }
+
+        if share_value <= self.high_water_mark {
+            return Ok(0);
         }
 
-        // Add the position to the array
-        let position_count = self.position_count as usize;
-        self.positions[position_count] = position;
-        self.position_count += 1;
+        // Fee shares do not pay the fee on themselves
+        let charged_shares = self
+            .strategy_shares
+            .checked_sub(self.fee_shares)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        let value_gain = share_value - self.high_water_mark;
+
+        // The fee value is charged_shares * value_gain * performance_fee_bps / (SHARE_PRECISION * BASIS_POINT_MAX).
+        // Minted at the share value after the fee: fee_shares = fee_value * strategy_shares / (total_strategy_value - fee_value),
+        // rounded up
+        let fee_numerator = U256::from(charged_shares)
+            .checked_mul(U256::from(value_gain))
+            .ok_or(MaikerError::ArithmeticOverflow)?
+            .checked_mul(U256::from(performance_fee_bps))
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        let value_numerator = U256::from(total_strategy_value)
+            .checked_mul(U256::from(SHARE_PRECISION))
+            .ok_or(MaikerError::ArithmeticOverflow)?
+            .checked_mul(U256::from(BASIS_POINT_MAX as u32))
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        let denominator = value_numerator
+            .checked_sub(fee_numerator)
+            .filter(|denominator| *denominator > U256::ZERO)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+
+        let fee_shares: u64 = fee_numerator
+            .checked_mul(U256::from(self.strategy_shares))
+            .ok_or(MaikerError::ArithmeticOverflow)?
+            .div_ceil(denominator)
+            .try_into()
+            .map_err(|_| MaikerError::ArithmeticOverflow)?;
+
+        self.mint_shares(fee_shares)?;
+        self.add_fee_shares(fee_shares)?;
+
+        // Rounding up the fee shares can leave the share value just below the previous mark
+        self.high_water_mark = self
+            .calculate_share_value(total_strategy_value, Rounding::Down)?
+            .max(self.high_water_mark);
+
+        Ok(fee_shares)
+    }
+
+    pub fn add_pending_withdrawal(&mut self) -> Result<()> {
+        self.pending_withdrawal_count = self
+            .pending_withdrawal_count
+            .checked_add(1)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
 
         Ok(())
     }
 
-    /// Removes a position from the strategy by swapping with the last position
-    /// This is more gas efficient but does not preserve position order
-    pub fn remove_position(&mut self, position: Pubkey) -> Result<()> {
-        // Find the position in the array
-        for i in 0..self.position_count as usize {
-            if self.positions[i] == position {
-                // If this is not the last position, swap with the last position
-                if i < self.position_count as usize - 1 {
-                    self.positions[i] = self.positions[self.position_count as usize - 1];
-                    self.positions_values[i] =
-                        self.positions_values[self.position_count as usize - 1];
-                    self.last_position_update[i] =
-                        self.last_position_update[self.position_count as usize - 1];
-                }
+    pub fn remove_pending_withdrawal(&mut self) -> Result<()> {
+        self.pending_withdrawal_count = self
+            .pending_withdrawal_count
+            .checked_sub(1)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
 
-                // Clear the last position
-                let last_index = self.position_count as usize - 1;
-                self.positions[last_index] = Pubkey::default();
-                self.positions_values[last_index] = 0;
-                self.last_position_update[last_index] = 0;
+        Ok(())
+    }
+
+    /// Validates that the strategy holds nothing owed to users, the treasury or a DLMM position.
+    /// Only the dead shares locked by the first deposit may remain.
+    pub fn validate_closable(&self) -> Result<()> {
+        validate!(
+            self.strategy_shares <= DEAD_SHARES,
+            MaikerError::StrategyNotEmpty,
+            "Strategy still has {} shares",
+            self.strategy_shares
+        )?;
+        validate!(
+            self.fee_shares == 0,
+            MaikerError::StrategyNotEmpty,
+            "Strategy still has {} fee shares",
+            self.fee_shares
+        )?;
+        validate!(
+            self.position_count == 0,
+            MaikerError::StrategyNotEmpty,
+            "Strategy still has {} positions",
+            self.position_count
+        )?;
+        validate!(
+            self.pending_withdrawal_count == 0,
+            MaikerError::StrategyNotEmpty,
+            "Strategy still has {} pending withdrawals",
+            self.pending_withdrawal_count
+        )?;
+        validate!(
+            !self.is_swapping,
+            MaikerError::StrategyNotEmpty,
+            "Swap in progress"
+        )?;
 
-                // Decrement the count
-                self.position_count -= 1;
+        Ok(())
+    }
 
-                return Ok(());
-            }
-        }
+    /// Counts a new position of the strategy
+    pub fn add_position(&mut self) -> Result<()> {
+        self.position_count = self
+            .position_count
+            .checked_add(1)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
 
-        Err(error!(MaikerError::PositionNotFound))
+        Ok(())
     }
 
-    pub fn update_position_value(&mut self, position: Pubkey, value: u64, slot: u64) {
-        for i in 0..self.position_count as usize {
-            if self.positions[i] == position {
-                self.positions_values[i] = value;
-                self.last_position_update[i] = slot;
-                break;
-            }
+    /// Whether the position value is counted in the current valuation round
+    fn is_valued_in_round(&self, strategy_position: &StrategyPosition) -> bool {
+        strategy_position.valuation_round != 0
+            && strategy_position.valuation_round == self.valuation_round
+    }
+
+    /// Removes a position from the strategy, together with its value if it was counted
+    /// in the current valuation round
+    pub fn remove_position(&mut self, strategy_position: &StrategyPosition) -> Result<()> {
+        require!(self.position_count > 0, MaikerError::PositionNotFound);
+        self.position_count -= 1;
+
+        if self.is_valued_in_round(strategy_position) {
+            self.valued_position_count = self.valued_position_count.saturating_sub(1);
+            self.valued_positions_value = self
+                .valued_positions_value
+                .checked_sub(strategy_position.value)
+                .ok_or(MaikerError::ArithmeticOverflow)?;
         }
+
+        Ok(())
     }
 
-    /// Validates that all active positions have their values updated in the current slot
-    pub fn validate_position_values_freshness(&self, slot: u64) -> Result<()> {
-        for i in 0..self.position_count as usize {
-            let position_pubkey = self.positions[i];
+    /// Records the value of a position. The first valuation in a slot starts a new round, and
+    /// once every position was valued in the round the cached total is refreshed as well.
+    pub fn update_position_value(
+        &mut self,
+        strategy_position: &mut StrategyPosition,
+        value: u64,
+        slot: u64,
+    ) -> Result<()> {
+        if self.valuation_slot != slot {
+            self.valuation_round = self
+                .valuation_round
+                .checked_add(1)
+                .ok_or(MaikerError::ArithmeticOverflow)?;
+            self.valuation_slot = slot;
+            self.valued_position_count = 0;
+            self.valued_positions_value = 0;
+        }
 
-            // Skip empty position slots
-            if position_pubkey == Pubkey::default() {
-                continue;
-            }
+        if self.is_valued_in_round(strategy_position) {
+            // Valued again in the same round, replace its previous value
+            self.valued_positions_value = self
+                .valued_positions_value
+                .checked_sub(strategy_position.value)
+                .ok_or(MaikerError::ArithmeticOverflow)?;
+        } else {
+            self.valued_position_count = self
+                .valued_position_count
+                .checked_add(1)
+                .ok_or(MaikerError::ArithmeticOverflow)?;
+            strategy_position.valuation_round = self.valuation_round;
+        }
 
-            // Verify this position was updated in the current slot
-            require!(
-                self.last_position_update[i] == slot,
-                MaikerError::StalePositionValue
-            );
+        self.valued_positions_value = self
+            .valued_positions_value
+            .checked_add(value)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        strategy_position.value = value;
+        strategy_position.value_slot = slot;
+
+        if self.valued_position_count == self.position_count {
+            self.total_value = self.valued_positions_value;
+            self.value_slot = slot;
         }
 
         Ok(())
     }
 
-    /// Calculate the total value of all positions in the strategy
-    fn get_total_positions_value(&self) -> Result<u64> {
-        let mut total_value: u64 = 0;
+    /// Validates that the cached total value was refreshed in the current slot
+    pub fn validate_strategy_value_freshness(&self, slot: u64) -> Result<()> {
+        // Without positions the strategy value is held entirely in the vault
+        if self.position_count == 0 {
+            return Ok(());
+        }
 
-        for i in 0..self.position_count as usize {
-            let position_pubkey = self.positions[i];
+        require!(self.value_slot == slot, MaikerError::StalePositionValue);
 
-            // Skip empty position slots
-            if position_pubkey == Pubkey::default() {
-                continue;
-            }
+        Ok(())
+    }
 
-            total_value = total_value
-                .checked_add(self.positions_values[i])
-                .ok_or(MaikerError::ArithmeticOverflow)?;
-        }
+    /// Records a rebalance and invalidates every cached position value.
+    /// Values refreshed earlier in the same slot were observed before the rebalance, so they
+    /// must be refreshed again before deposits or withdrawals can use them.
+    pub fn mark_rebalance(&mut self, slot: u64, timestamp: i64) {
+        self.last_rebalance_slot = slot;
+        self.last_rebalance_time = timestamp;
 
-        Ok(total_value)
+        // The next valuation starts a new round, even within this slot
+        self.valuation_slot = 0;
+        self.value_slot = 0;
     }
 
-    /// Calculate the total strategy value (vault value + positions value)
+    /// Calculate the total strategy value (vault value + cached positions value)
     pub fn calculate_total_strategy_value(&self, vault_x_amount: u64) -> Result<u64> {
-        let positions_value = self.get_total_positions_value()?;
+        let positions_value = if self.position_count == 0 {
+            0
+        } else {
+            self.total_value
+        };
 
         let total_value = vault_x_amount
             .checked_add(positions_value)
@@ -230,33 +664,60 @@ impl StrategyConfig {
         Ok(total_value)
     }
 
-    /// Calculate the current share value based on total strategy value and total shares
-    pub fn calculate_share_value(&self, total_strategy_value: u64) -> Result<u64> {
+    /// Calculate the current share value based on total strategy value and total shares.
+    /// Deposits price shares rounded up and withdrawals rounded down, so both round against the user.
+    pub fn calculate_share_value(
+        &self,
+        total_strategy_value: u64,
+        rounding: Rounding,
+    ) -> Result<u128> {
         if self.strategy_shares == 0 {
             return Ok(SHARE_PRECISION); // Default to 1.0 if no shares exist
         }
 
-        let share_value = (total_strategy_value as u128)
-            .checked_mul(SHARE_PRECISION as u128)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_div(self.strategy_shares as u128)
-            .ok_or(MaikerError::ArithmeticOverflow)? as u64;
+        safe_mul_div_cast(
+            total_strategy_value as u128,
+            SHARE_PRECISION,
+            self.strategy_shares as u128,
+            rounding,
+        )
+    }
 
-        Ok(share_value)
+    /// Calculate the depositor's shares for the first deposit at a share value of 1:1.
+    /// DEAD_SHARES of the deposit are minted to nobody and stay in the strategy forever.
+    pub fn calculate_shares_for_initial_deposit(&self, deposit_value: u64) -> Result<u64> {
+        validate!(
+            deposit_value > DEAD_SHARES,
+            MaikerError::InvalidDepositAmount,
+            "Initial deposit must exceed {}",
+            DEAD_SHARES
+        )?;
+
+        Ok(deposit_value - DEAD_SHARES)
     }
 
     /// Calculate shares to mint for a deposit
     pub fn calculate_shares_for_deposit(
         &self,
         deposit_value: u64,
-        current_share_value: u64,
+        current_share_value: u128,
     ) -> Result<u64> {
-        // Formula: new_shares = (deposit_value * SHARE_PRECISION) / current_share_value
-        let new_shares = deposit_value
-            .checked_mul(SHARE_PRECISION)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_div(current_share_value)
-            .ok_or(MaikerError::ArithmeticOverflow)?;
+        // Formula: new_shares = (deposit_value * SHARE_PRECISION) / current_share_value, rounded down
+        let new_shares: u64 = safe_mul_div_cast(
+            deposit_value as u128,
+            SHARE_PRECISION,
+            current_share_value,
+            Rounding::Down,
+        )?;
+
+        // Reject deposits that would be absorbed by the existing shares
+        validate!(
+            new_shares > 0,
+            MaikerError::ZeroSharesMinted,
+            "Deposit of {} mints no shares at share value {}",
+            deposit_value,
+            current_share_value
+        )?;
 
         Ok(new_shares)
     }
@@ -265,16 +726,15 @@ impl StrategyConfig {
     pub fn calculate_withdrawal_amount(
         &self,
         shares_amount: u64,
-        current_share_value: u64,
+        current_share_value: u128,
     ) -> Result<u64> {
-        // Formula: token_amount = (shares_amount * current_share_value) / SHARE_PRECISION
-        let token_amount = (shares_amount as u128)
-            .checked_mul(current_share_value as u128)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_div(SHARE_PRECISION as u128)
-            .ok_or(MaikerError::ArithmeticOverflow)? as u64;
-
-        Ok(token_amount)
+        // Formula: token_amount = (shares_amount * current_share_value) / SHARE_PRECISION, rounded down
+        safe_mul_div_cast(
+            shares_amount as u128,
+            current_share_value,
+            SHARE_PRECISION,
+            Rounding::Down,
+        )
     }
 
     /// Starts tracking an active swap
@@ -339,3 +799,431 @@ impl StrategyConfig {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UserPosition;
+    use proptest::prelude::*;
+
+    fn empty_strategy() -> StrategyConfig {
+        StrategyConfig::deserialize(&mut &[0u8; StrategyConfig::INIT_SPACE][..]).unwrap()
+    }
+
+    /// Runs a deposit against the strategy the same way the deposit instruction does
+    fn deposit(strategy: &mut StrategyConfig, vault_amount: &mut u64, amount: u64) -> Result<u64> {
+        let total_strategy_value = strategy.calculate_total_strategy_value(*vault_amount)?;
+        let new_shares = if strategy.strategy_shares == 0 {
+            let new_shares = strategy.calculate_shares_for_initial_deposit(amount)?;
+            strategy.mint_shares(DEAD_SHARES)?;
+            new_shares
+        } else {
+            let share_value = strategy.calculate_share_value(total_strategy_value, Rounding::Up)?;
+            strategy.calculate_shares_for_deposit(amount, share_value)?
+        };
+        strategy.mint_shares(new_shares)?;
+        *vault_amount += amount;
+
+        Ok(new_shares)
+    }
+
+    #[test]
+    fn locks_dead_shares_on_initial_deposit() {
+        let mut strategy = empty_strategy();
+        let mut vault_amount = 0;
+
+        assert!(deposit(&mut strategy, &mut vault_amount, DEAD_SHARES).is_err());
+
+        let shares = deposit(&mut strategy, &mut vault_amount, 1_000_000).unwrap();
+        assert_eq!(shares, 1_000_000 - DEAD_SHARES);
+        assert_eq!(strategy.strategy_shares, 1_000_000);
+        assert_eq!(
+            strategy
+                .calculate_share_value(vault_amount, Rounding::Down)
+                .unwrap(),
+            SHARE_PRECISION
+        );
+    }
+
+    #[test]
+    fn resists_donation_attack() {
+        let mut strategy = empty_strategy();
+        let mut vault_amount = 0;
+
+        // The attacker takes a single share and donates to the vault to inflate the share value
+        let attacker_shares = deposit(&mut strategy, &mut vault_amount, DEAD_SHARES + 1).unwrap();
+        assert_eq!(attacker_shares, 1);
+        let donation = 1_000_000_000;
+        vault_amount += donation;
+
+        // A victim deposit too small for a single share is rejected rather than absorbed
+        assert_eq!(
+            deposit(&mut strategy, &mut vault_amount, 100_000).unwrap_err(),
+            MaikerError::ZeroSharesMinted.into()
+        );
+
+        // A larger victim deposit still gets shares in proportion to its value
+        let victim_amount = 10_000_000_000;
+        let victim_shares = deposit(&mut strategy, &mut vault_amount, victim_amount).unwrap();
+        let share_value = strategy
+            .calculate_share_value(vault_amount, Rounding::Down)
+            .unwrap();
+        let victim_value = strategy
+            .calculate_withdrawal_amount(victim_shares, share_value)
+            .unwrap();
+        assert!(victim_value >= victim_amount - victim_amount / 100);
+
+        // Most of the donation accrues to the dead shares, so the attack is unprofitable
+        let attacker_value = strategy
+            .calculate_withdrawal_amount(attacker_shares, share_value)
+            .unwrap();
+        assert!(attacker_value < (DEAD_SHARES + 1 + donation) / 100);
+    }
+
+    #[test]
+    fn signer_seeds_match_strategy_address() {
+        let mut strategy = empty_strategy();
+        strategy.creator = Pubkey::new_unique();
+        strategy.x_mint = Pubkey::new_unique();
+        strategy.y_mint = Pubkey::new_unique();
+        strategy.strategy_id = 258;
+
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                StrategyConfig::SEED_PREFIX.as_bytes(),
+                strategy.creator.as_ref(),
+                strategy.x_mint.as_ref(),
+                strategy.y_mint.as_ref(),
+                strategy.strategy_id.to_le_bytes().as_ref(),
+            ],
+            &crate::id(),
+        );
+        strategy.bump = bump;
+
+        assert_eq!(
+            Pubkey::create_program_address(&strategy.get_pda_signer(), &crate::id()).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn signer_seeds_match_legacy_strategy_address() {
+        let mut strategy = empty_strategy();
+        strategy.creator = Pubkey::new_unique();
+        strategy.x_mint = Pubkey::new_unique();
+        strategy.y_mint = Pubkey::new_unique();
+        strategy.legacy_seeds = true;
+
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                StrategyConfig::SEED_PREFIX.as_bytes(),
+                strategy.creator.as_ref(),
+                strategy.x_mint.as_ref(),
+                strategy.y_mint.as_ref(),
+            ],
+            &crate::id(),
+        );
+        strategy.bump = bump;
+
+        assert_eq!(
+            Pubkey::create_program_address(&strategy.get_pda_signer(), &crate::id()).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn deposits_above_u64_precision_do_not_overflow() {
+        let mut strategy = empty_strategy();
+        let mut vault_amount = 0;
+
+        deposit(&mut strategy, &mut vault_amount, 1_000_000).unwrap();
+        let amount = 1_000_000_000_000_000_000;
+        let shares = deposit(&mut strategy, &mut vault_amount, amount).unwrap();
+        assert_eq!(shares, amount);
+    }
+
+    fn strategy_position() -> StrategyPosition {
+        StrategyPosition::deserialize(&mut &[0u8; StrategyPosition::INIT_SPACE][..]).unwrap()
+    }
+
+    #[test]
+    fn refreshes_total_value_once_every_position_is_valued() {
+        let mut strategy = empty_strategy();
+        let mut first = strategy_position();
+        let mut second = strategy_position();
+        strategy.add_position().unwrap();
+        strategy.add_position().unwrap();
+
+        // Valuing the same position twice counts it once, with its latest value
+        strategy.update_position_value(&mut first, 100, 10).unwrap();
+        strategy.update_position_value(&mut first, 150, 10).unwrap();
+        assert!(strategy.validate_strategy_value_freshness(10).is_err());
+
+        strategy.update_position_value(&mut second, 50, 10).unwrap();
+        strategy.validate_strategy_value_freshness(10).unwrap();
+        assert_eq!(strategy.total_value, 200);
+
+        // A rebalance requires every position to be valued again, even within the slot
+        strategy.mark_rebalance(10, 0);
+        strategy.update_position_value(&mut first, 120, 10).unwrap();
+        assert!(strategy.validate_strategy_value_freshness(10).is_err());
+        strategy.update_position_value(&mut second, 60, 10).unwrap();
+        strategy.validate_strategy_value_freshness(10).unwrap();
+        assert_eq!(strategy.total_value, 180);
+
+        // Values of a previous slot are not carried over
+        strategy.update_position_value(&mut second, 70, 11).unwrap();
+        assert!(strategy.validate_strategy_value_freshness(11).is_err());
+
+        // Removing the unvalued position completes the round
+        strategy.remove_position(&first).unwrap();
+        strategy.update_position_value(&mut second, 70, 11).unwrap();
+        strategy.validate_strategy_value_freshness(11).unwrap();
+        assert_eq!(strategy.total_value, 70);
+    }
+
+    #[test]
+    fn removes_positions_that_were_never_valued() {
+        // Migrated positions keep their last value, but were never counted in a round,
+        // just like the positions of oracle priced strategies
+        for valuation_round in [0, 1] {
+            let mut strategy = empty_strategy();
+            strategy.valuation_round = valuation_round;
+            let mut position = strategy_position();
+            position.value = 42;
+            strategy.add_position().unwrap();
+
+            strategy.remove_position(&position).unwrap();
+            assert_eq!(strategy.position_count, 0);
+            assert_eq!(strategy.valued_position_count, 0);
+            assert_eq!(strategy.valued_positions_value, 0);
+        }
+    }
+
+    #[test]
+    fn only_approved_creators_operate_their_strategies() {
+        let mut strategy = empty_strategy();
+        strategy.creator = Pubkey::new_unique();
+        let mut global_config =
+            GlobalConfig::deserialize(&mut &[0u8; GlobalConfig::INIT_SPACE][..]).unwrap();
+        global_config.admin = Pubkey::new_unique();
+        let mut strategy_creator =
+            StrategyCreator::deserialize(&mut &[0u8; StrategyCreator::INIT_SPACE][..]).unwrap();
+        strategy_creator.creator = strategy.creator;
+
+        assert!(strategy.is_operator(global_config.admin, &global_config, None));
+        assert!(strategy.is_operator(strategy.creator, &global_config, Some(&strategy_creator)));
+
+        // A removed creator no longer has a registry entry
+        assert!(!strategy.is_operator(strategy.creator, &global_config, None));
+
+        // The registry entry of another creator does not count
+        let other_creator = Pubkey::new_unique();
+        strategy_creator.creator = other_creator;
+        assert!(!strategy.is_operator(other_creator, &global_config, Some(&strategy_creator)));
+        assert!(!strategy.is_operator(strategy.creator, &global_config, Some(&strategy_creator)));
+    }
+
+    #[test]
+    fn enforces_liquidity_limits_only_when_set() {
+        let mut strategy = empty_strategy();
+        strategy.validate_liquidity_bins(-1_000, 1_000, 0).unwrap();
+
+        strategy.max_liquidity_bin_width = 70;
+        strategy.max_active_bin_distance = 40;
+        strategy.validate_liquidity_bins(-35, 34, 0).unwrap();
+        strategy.validate_liquidity_bins(10, 40, 0).unwrap();
+
+        for (min_bin_id, max_bin_id, active_id) in [(-35, 35, 0), (10, 41, 0), (-50, -45, 0)] {
+            assert_eq!(
+                strategy
+                    .validate_liquidity_bins(min_bin_id, max_bin_id, active_id)
+                    .err(),
+                Some(MaikerError::LiquidityLimitExceeded.into())
+            );
+        }
+        assert_eq!(
+            strategy.validate_liquidity_bins(5, 4, 0).err(),
+            Some(MaikerError::InvalidBinId.into())
+        );
+    }
+
+    /// A strategy holding total_strategy_value with 1_000_000 shares, charged up to a share value of 1.0
+    fn strategy_with_value(total_strategy_value: u64) -> (StrategyConfig, u64) {
+        let mut strategy = empty_strategy();
+        strategy.strategy_shares = 1_000_000;
+        strategy.high_water_mark = SHARE_PRECISION;
+        (strategy, total_strategy_value)
+    }
+
+    #[test]
+    fn charges_the_performance_fee_once_per_gain() {
+        // The share value doubled, 10% of the gain is minted as fee shares at the share value after the fee
+        let (mut strategy, total_strategy_value) = strategy_with_value(2_000_000);
+        let fee_shares = strategy
+            .crystallize_performance_fee(total_strategy_value, 1_000)
+            .unwrap();
+        assert_eq!(fee_shares, 52_632);
+        assert_eq!(strategy.fee_shares, fee_shares);
+        assert_eq!(strategy.strategy_shares, 1_052_632);
+        let share_value = strategy
+            .calculate_share_value(total_strategy_value, Rounding::Down)
+            .unwrap();
+        assert_eq!(strategy.high_water_mark, share_value);
+        assert_eq!(
+            strategy
+                .calculate_withdrawal_amount(fee_shares, share_value)
+                .unwrap(),
+            100_000
+        );
+
+        // The same gain is not charged twice, nor a recovery up to the mark
+        assert_eq!(
+            strategy
+                .crystallize_performance_fee(total_strategy_value, 1_000)
+                .unwrap(),
+            0
+        );
+        strategy
+            .crystallize_performance_fee(1_500_000, 1_000)
+            .unwrap();
+        assert_eq!(
+            strategy
+                .crystallize_performance_fee(total_strategy_value, 1_000)
+                .unwrap(),
+            0
+        );
+        assert_eq!(strategy.fee_shares, fee_shares);
+
+        // Migrated strategies start charging from their first valuation
+        let (mut strategy, total_strategy_value) = strategy_with_value(2_000_000);
+        strategy.high_water_mark = 0;
+        assert_eq!(
+            strategy
+                .crystallize_performance_fee(total_strategy_value, 1_000)
+                .unwrap(),
+            0
+        );
+        assert_eq!(strategy.high_water_mark, 2 * SHARE_PRECISION);
+    }
+
+    #[test]
+    fn out_of_band_share_transfers_do_not_lower_the_performance_fee() {
+        let (mut strategy, total_strategy_value) = strategy_with_value(2_000_000);
+        let expected_fee_shares = strategy
+            .clone()
+            .crystallize_performance_fee(total_strategy_value, 1_000)
+            .unwrap();
+
+        // The holder bought at 1.0 and moves the share tokens to a fresh wallet by a plain token transfer,
+        // which books them at the current share value of 2.0
+        let mut holder =
+            UserPosition::deserialize(&mut &[0u8; UserPosition::INIT_SPACE][..]).unwrap();
+        holder.receive_shares(1_000_000, SHARE_PRECISION).unwrap();
+        let mut fresh_wallet = holder.clone();
+        fresh_wallet.strategy_share = 0;
+        holder.sync_share_balance(0, 2 * SHARE_PRECISION).unwrap();
+        fresh_wallet
+            .sync_share_balance(1_000_000, 2 * SHARE_PRECISION)
+            .unwrap();
+        assert_eq!(fresh_wallet.last_share_value, 2 * SHARE_PRECISION);
+
+        // The fee only depends on the strategy share value, so the full gain is still charged
+        assert_eq!(
+            strategy
+                .crystallize_performance_fee(total_strategy_value, 1_000)
+                .unwrap(),
+            expected_fee_shares
+        );
+    }
+
+    proptest! {
+        /// Minting and redeeming against the exact reference model shares = deposit * S / T
+        #[test]
+        fn share_math_rounds_against_the_user(
+            total_value in 1u64..1_000_000_000_000_000,
+            total_shares in DEAD_SHARES..1_000_000_000_000_000,
+            deposit_value in 1u64..1_000_000_000_000_000,
+        ) {
+            let mut strategy = empty_strategy();
+            strategy.strategy_shares = total_shares;
+
+            // Mint rounds down: never more shares than the exact amount
+            let mint_share_value = strategy.calculate_share_value(total_value, Rounding::Up).unwrap();
+            let exact_shares = deposit_value as u128 * total_shares as u128 / total_value as u128;
+            let shares = match strategy.calculate_shares_for_deposit(deposit_value, mint_share_value) {
+                Ok(shares) => shares as u128,
+                Err(_) => {
+                    // Rejected as zero shares, or more shares than fit in a u64
+                    prop_assert!(exact_shares == 0 || exact_shares >= u64::MAX as u128);
+                    return Ok(());
+                }
+            };
+            prop_assert!(shares <= exact_shares);
+
+            // The share value rounding costs less than a share while it is below SHARE_PRECISION
+            let rounding_error = U256::from(deposit_value)
+                * U256::from(total_shares)
+                * U256::from(total_shares);
+            if rounding_error < U256::from(total_value) * U256::from(total_value) * U256::from(SHARE_PRECISION) {
+                prop_assert!(shares + 1 >= exact_shares);
+            }
+
+            // Redeem rounds down: never more tokens than the exact amount, and at most 1 less while the
+            // share value rounding applies to less than SHARE_PRECISION shares
+            let redeem_share_value = strategy.calculate_share_value(total_value, Rounding::Down).unwrap();
+            let amount = strategy.calculate_withdrawal_amount(shares as u64, redeem_share_value).unwrap() as u128;
+            let exact_amount = shares * total_value as u128 / total_shares as u128;
+            prop_assert!(amount <= exact_amount);
+            if shares < SHARE_PRECISION {
+                prop_assert!(amount + 1 >= exact_amount);
+            }
+
+            // A deposit can never be redeemed for more than was deposited
+            strategy.mint_shares(shares as u64).unwrap();
+            let share_value = strategy
+                .calculate_share_value(total_value + deposit_value, Rounding::Down)
+                .unwrap();
+            let redeemed = strategy.calculate_withdrawal_amount(shares as u64, share_value).unwrap();
+            prop_assert!(redeemed <= deposit_value);
+        }
+
+        /// The performance fee shares are worth the exact reference fee after the fee, rounded up
+        #[test]
+        fn performance_fee_rounds_up(
+            strategy_shares in DEAD_SHARES..1_000_000_000_000_000,
+            fee_shares in 0u64..DEAD_SHARES,
+            total_strategy_value in 1u64..1_000_000_000_000_000,
+            high_water_mark in 1u128..1_000_000 * SHARE_PRECISION,
+            performance_fee_bps in 0u16..=10_000,
+        ) {
+            let mut strategy = empty_strategy();
+            strategy.strategy_shares = strategy_shares;
+            strategy.fee_shares = fee_shares;
+            strategy.high_water_mark = high_water_mark;
+            let share_value = strategy.calculate_share_value(total_strategy_value, Rounding::Down).unwrap();
+
+            let minted = strategy
+                .crystallize_performance_fee(total_strategy_value, performance_fee_bps)
+                .unwrap();
+            if share_value <= high_water_mark {
+                prop_assert_eq!(minted, 0);
+                return Ok(());
+            }
+
+            // minted * total_strategy_value / (strategy_shares + minted) >= fee value, and one share less is not
+            let fee_value = U256::from(strategy_shares - fee_shares)
+                * U256::from(share_value - high_water_mark)
+                * U256::from(performance_fee_bps);
+            let scale = U256::from(total_strategy_value)
+                * U256::from(SHARE_PRECISION)
+                * U256::from(BASIS_POINT_MAX as u32);
+            prop_assert!(U256::from(minted) * scale >= fee_value * U256::from(strategy_shares + minted));
+            if minted > 0 {
+                prop_assert!(U256::from(minted - 1) * scale < fee_value * U256::from(strategy_shares + minted - 1));
+            }
+            prop_assert_eq!(strategy.fee_shares, fee_shares + minted);
+            prop_assert!(strategy.high_water_mark >= high_water_mark);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::MaikerError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct StrategyCreator {
+    pub version: u8,         // Layout version, see migrate_* instructions
+    pub creator: Pubkey,     // Creator approved by the admin
+    pub strategy_count: u64, // Number of strategies created by the creator
+    pub bump: u8,            // PDA bump
+    pub reserved: [u8; 32],  // Space for fields added in later versions
+}
+
+impl StrategyCreator {
+    pub const SEED_PREFIX: &'static str = "strategy-creator";
+    pub const VERSION: u8 = 1;
+
+    pub fn initialize(&mut self, creator: Pubkey, bump: u8) {
+        self.version = Self::VERSION;
+        self.creator = creator;
+        self.strategy_count = 0;
+        self.bump = bump;
+    }
+
+    pub fn add_strategy(&mut self) -> Result<()> {
+        self.strategy_count = self
+            .strategy_count
+            .checked_add(1)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    validate, MaikerError, MAX_STRATEGY_NAME_LEN, MAX_STRATEGY_SYMBOL_LEN, MAX_STRATEGY_URI_LEN,
+    MAX_TARGET_PAIRS,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StrategyCategory {
+    Other,
+    Stable,     // Pairs of pegged assets
+    Correlated, // Pairs of correlated assets, e.g. LSTs
+    Volatile,   // Pairs of uncorrelated assets
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct StrategyMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub category: StrategyCategory,
+    pub target_pairs: Vec<Pubkey>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct StrategyMetadata {
+    pub version: u8,      // Layout version, see migrate_* instructions
+    pub strategy: Pubkey, // Strategy described by the metadata
+    #[max_len(MAX_STRATEGY_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_STRATEGY_SYMBOL_LEN)]
+    pub symbol: String,
+    #[max_len(MAX_STRATEGY_URI_LEN)]
+    pub uri: String, // Off-chain description of the strategy
+    pub category: StrategyCategory,
+    #[max_len(MAX_TARGET_PAIRS)]
+    pub target_pairs: Vec<Pubkey>, // DLMM lb_pairs the strategy provides liquidity to
+    pub bump: u8,           // PDA bump
+    pub reserved: [u8; 64], // Space for fields added in later versions
+}
+
+impl StrategyMetadata {
+    pub const SEED_PREFIX: &'static str = "strategy-metadata";
+    pub const VERSION: u8 = 1;
+
+    pub fn update_strategy_metadata(
+        &mut self,
+        strategy: Pubkey,
+        args: StrategyMetadataArgs,
+        bump: u8,
+    ) -> Result<()> {
+        validate!(
+            args.name.len() <= MAX_STRATEGY_NAME_LEN,
+            MaikerError::InvalidStrategyMetadata,
+            "Name must be at most {} bytes",
+            MAX_STRATEGY_NAME_LEN
+        )?;
+        validate!(
+            args.symbol.len() <= MAX_STRATEGY_SYMBOL_LEN,
+            MaikerError::InvalidStrategyMetadata,
+            "Symbol must be at most {} bytes",
+            MAX_STRATEGY_SYMBOL_LEN
+        )?;
+        validate!(
+            args.uri.len() <= MAX_STRATEGY_URI_LEN,
+            MaikerError::InvalidStrategyMetadata,
+            "Uri must be at most {} bytes",
+            MAX_STRATEGY_URI_LEN
+        )?;
+        validate!(
+            args.target_pairs.len() <= MAX_TARGET_PAIRS,
+            MaikerError::InvalidStrategyMetadata,
+            "At most {} target pairs",
+            MAX_TARGET_PAIRS
+        )?;
+        validate!(
+            !args.target_pairs.contains(&Pubkey::default()),
+            MaikerError::InvalidStrategyMetadata,
+            "Invalid target pair"
+        )?;
+
+        self.version = Self::VERSION;
+        self.strategy = strategy;
+        self.name = args.name;
+        self.symbol = args.symbol;
+        self.uri = args.uri;
+        self.category = args.category;
+        self.target_pairs = args.target_pairs;
+        self.bump = bump;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+
+use crate::{validate, MaikerError, BASIS_POINT_MAX, DLMM_POSITION_SEED};
+
+#[account]
+#[derive(InitSpace)]
+pub struct StrategyPosition {
+    pub version: u8,      // Layout version, see migrate_* instructions
+    pub strategy: Pubkey, // Strategy owning the DLMM position
+    pub position: Pubkey, // DLMM position
+    pub lb_pair: Pubkey,  // lb_pair of the DLMM position
+    pub lower_bin_id: i32,
+    pub upper_bin_id: i32,
+
+    // Valuation
+    pub value: u64,           // Position value in token X
+    pub value_slot: u64,      // Slot of the last valuation
+    pub valuation_round: u64, // Valuation round of the strategy the value was counted in, 0 if never valued
+
+    // Analytics
+    pub cost_basis_x: u64, // Token X added to the position since it was last emptied
+    pub cost_basis_y: u64, // Token Y added to the position since it was last emptied
+    pub accrued_fee_x: u64, // Token X fees claimed from the position
+    pub accrued_fee_y: u64, // Token Y fees claimed from the position
+
+    pub bump: u8,
+
+    // DLMM position created by initialize_position_pda, its address is derived from the strategy and bin range.
+    // Taken from the reserved space, false in older accounts
+    pub derived: bool,
+    pub position_bump: u8, // Bump of the DLMM position PDA, set together with derived
+
+    pub reserved: [u8; 62], // Space for fields added in later versions
+}
+
+impl StrategyPosition {
+    pub const SEED_PREFIX: &'static str = "strategy-position";
+    pub const VERSION: u8 = 1;
+
+    pub fn initialize(
+        &mut self,
+        strategy: Pubkey,
+        position: Pubkey,
+        lb_pair: Pubkey,
+        lower_bin_id: i32,
+        upper_bin_id: i32,
+        bump: u8,
+    ) {
+        self.version = Self::VERSION;
+        self.strategy = strategy;
+        self.position = position;
+        self.lb_pair = lb_pair;
+        self.lower_bin_id = lower_bin_id;
+        self.upper_bin_id = upper_bin_id;
+        self.value = 0;
+        self.value_slot = 0;
+        self.valuation_round = 0;
+        self.cost_basis_x = 0;
+        self.cost_basis_y = 0;
+        self.accrued_fee_x = 0;
+        self.accrued_fee_y = 0;
+        self.bump = bump;
+        self.derived = false;
+        self.position_bump = 0;
+    }
+
+    /// Marks the DLMM position as the PDA of the strategy and bin range
+    pub fn set_derived(&mut self, position_bump: u8) {
+        self.derived = true;
+        self.position_bump = position_bump;
+    }
+
+    /// Address and bump of the DLMM position PDA of a strategy, the strategy is the base of the derivation
+    pub fn derive_dlmm_position(
+        strategy: Pubkey,
+        lb_pair: Pubkey,
+        lower_bin_id: i32,
+        width: i32,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                DLMM_POSITION_SEED,
+                lb_pair.as_ref(),
+                strategy.as_ref(),
+                &lower_bin_id.to_le_bytes(),
+                &width.to_le_bytes(),
+            ],
+            &dlmm_interface::ID,
+        )
+    }
+
+    /// Whether the tracked position is the DLMM position PDA of its strategy and bin range.
+    /// Positions created from a keypair through initialize_position are not derivable.
+    pub fn is_derived_position(&self) -> bool {
+        self.position
+            == Self::derive_dlmm_position(
+                self.strategy,
+                self.lb_pair,
+                self.lower_bin_id,
+                self.upper_bin_id - self.lower_bin_id + 1,
+            )
+            .0
+    }
+
+    /// Positions created by initialize_position_pda have to match their derivation, which is
+    /// recomputed from the stored bump. Positions created from a keypair have no derivation to match.
+    pub fn has_valid_derivation(&self) -> bool {
+        if !self.derived {
+            return true;
+        }
+
+        let width = self.upper_bin_id - self.lower_bin_id + 1;
+        Pubkey::create_program_address(
+            &[
+                DLMM_POSITION_SEED,
+                self.lb_pair.as_ref(),
+                self.strategy.as_ref(),
+                &self.lower_bin_id.to_le_bytes(),
+                &width.to_le_bytes(),
+                &[self.position_bump],
+            ],
+            &dlmm_interface::ID,
+        )
+        .is_ok_and(|position| position == self.position)
+    }
+
+    pub fn add_liquidity(&mut self, amount_x: u64, amount_y: u64) -> Result<()> {
+        self.cost_basis_x = self
+            .cost_basis_x
+            .checked_add(amount_x)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        self.cost_basis_y = self
+            .cost_basis_y
+            .checked_add(amount_y)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Checks that a partial removal targets bins of the tracked position with a bps in (0, 10000]
+    pub fn validate_liquidity_reduction(
+        &self,
+        from_bin_id: i32,
+        to_bin_id: i32,
+        bps_to_remove: u16,
+    ) -> Result<()> {
+        validate!(
+            self.lower_bin_id <= from_bin_id
+                && from_bin_id <= to_bin_id
+                && to_bin_id <= self.upper_bin_id,
+            MaikerError::InvalidLiquidityReduction,
+            "Bins {} to {} are outside of the position range {} to {}",
+            from_bin_id,
+            to_bin_id,
+            self.lower_bin_id,
+            self.upper_bin_id
+        )?;
+        validate!(
+            bps_to_remove > 0 && i32::from(bps_to_remove) <= BASIS_POINT_MAX,
+            MaikerError::InvalidLiquidityReduction,
+            "Invalid bps to remove {}",
+            bps_to_remove
+        )?;
+
+        Ok(())
+    }
+
+    /// Lowers the cost basis by the tokens withdrawn in a partial removal
+    pub fn remove_liquidity(&mut self, amount_x: u64, amount_y: u64) {
+        self.cost_basis_x = self.cost_basis_x.saturating_sub(amount_x);
+        self.cost_basis_y = self.cost_basis_y.saturating_sub(amount_y);
+    }
+
+    /// Resets the cost basis once all liquidity left the position
+    pub fn remove_all_liquidity(&mut self) {
+        self.cost_basis_x = 0;
+        self.cost_basis_y = 0;
+    }
+
+    pub fn add_fees(&mut self, fee_x: u64, fee_y: u64) -> Result<()> {
+        self.accrued_fee_x = self
+            .accrued_fee_x
+            .checked_add(fee_x)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        self.accrued_fee_y = self
+            .accrued_fee_y
+            .checked_add(fee_y)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_derived_positions() {
+        let strategy = Pubkey::new_unique();
+        let lb_pair = Pubkey::new_unique();
+        let mut strategy_position =
+            StrategyPosition::deserialize(&mut &[0u8; StrategyPosition::INIT_SPACE][..]).unwrap();
+
+        let (position, _) = StrategyPosition::derive_dlmm_position(strategy, lb_pair, -35, 70);
+        strategy_position.initialize(strategy, position, lb_pair, -35, 34, 255);
+        assert!(strategy_position.is_derived_position());
+
+        // A keypair position, or a PDA of another bin range, does not match the tracked range
+        strategy_position.initialize(strategy, Pubkey::new_unique(), lb_pair, -35, 34, 255);
+        assert!(!strategy_position.is_derived_position());
+        let (position, _) = StrategyPosition::derive_dlmm_position(strategy, lb_pair, -35, 69);
+        strategy_position.initialize(strategy, position, lb_pair, -35, 34, 255);
+        assert!(!strategy_position.is_derived_position());
+    }
+
+    #[test]
+    fn rejects_pda_positions_that_do_not_match_their_derivation() {
+        let strategy = Pubkey::new_unique();
+        let lb_pair = Pubkey::new_unique();
+        let mut strategy_position =
+            StrategyPosition::deserialize(&mut &[0u8; StrategyPosition::INIT_SPACE][..]).unwrap();
+
+        let (position, position_bump) =
+            StrategyPosition::derive_dlmm_position(strategy, lb_pair, -35, 70);
+        strategy_position.initialize(strategy, position, lb_pair, -35, 34, 255);
+        strategy_position.set_derived(position_bump);
+        assert!(strategy_position.has_valid_derivation());
+
+        // Tracked as a PDA position, but the position or its range is not the derivation
+        strategy_position.initialize(strategy, Pubkey::new_unique(), lb_pair, -35, 34, 255);
+        strategy_position.set_derived(position_bump);
+        assert!(!strategy_position.has_valid_derivation());
+        strategy_position.initialize(strategy, position, lb_pair, -35, 33, 255);
+        strategy_position.set_derived(position_bump);
+        assert!(!strategy_position.has_valid_derivation());
+        strategy_position.initialize(strategy, position, Pubkey::new_unique(), -35, 34, 255);
+        strategy_position.set_derived(position_bump);
+        assert!(!strategy_position.has_valid_derivation());
+
+        strategy_position.initialize(strategy, position, lb_pair, -35, 34, 255);
+        strategy_position.set_derived(position_bump.wrapping_sub(1));
+        assert!(!strategy_position.has_valid_derivation());
+
+        // Keypair positions have no derivation to match
+        strategy_position.initialize(strategy, Pubkey::new_unique(), lb_pair, -35, 34, 255);
+        assert!(strategy_position.has_valid_derivation());
+    }
+
+    #[test]
+    fn validates_liquidity_reductions_against_the_position_range() {
+        let mut strategy_position =
+            StrategyPosition::deserialize(&mut &[0u8; StrategyPosition::INIT_SPACE][..]).unwrap();
+        strategy_position.initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            -35,
+            34,
+            255,
+        );
+
+        assert!(strategy_position
+            .validate_liquidity_reduction(-35, 34, 10_000)
+            .is_ok());
+        assert!(strategy_position
+            .validate_liquidity_reduction(0, 0, 1)
+            .is_ok());
+
+        for (from_bin_id, to_bin_id, bps_to_remove) in [
+            (-36, 0, 5_000),
+            (0, 35, 5_000),
+            (10, 0, 5_000),
+            (0, 10, 0),
+            (0, 10, 10_001),
+        ] {
+            assert_eq!(
+                strategy_position
+                    .validate_liquidity_reduction(from_bin_id, to_bin_id, bps_to_remove)
+                    .err(),
+                Some(MaikerError::InvalidLiquidityReduction.into())
+            );
+        }
+    }
+}
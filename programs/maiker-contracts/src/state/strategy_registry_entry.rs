@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Entry of the global strategy registry, keyed by the strategy index.
+/// Clients enumerate strategies by deriving the entries of indices `0..global_config.strategy_count`.
+/// Entries of closed strategies are closed with them.
+#[account]
+#[derive(InitSpace)]
+pub struct StrategyRegistryEntry {
+    pub version: u8,        // Layout version, see migrate_* instructions
+    pub strategy: Pubkey,   // Strategy registered at this index
+    pub bump: u8,           // PDA bump
+    pub reserved: [u8; 32], // Space for fields added in later versions
+}
+
+impl StrategyRegistryEntry {
+    pub const SEED_PREFIX: &'static str = "strategy-registry";
+    pub const VERSION: u8 = 1;
+
+    pub fn initialize(&mut self, strategy: Pubkey, bump: u8) {
+        self.version = Self::VERSION;
+        self.strategy = strategy;
+        self.bump = bump;
+    }
+}
@@ -1,19 +1,26 @@
-use crate::{MaikerError, SHARE_PRECISION};
+use crate::{
+    math::{u128x128_math::Rounding, utils_math::safe_mul_div_cast},
+    MaikerError, BASIS_POINT_MAX, SHARE_PRECISION,
+};
 use anchor_lang::prelude::*;
+use ruint::aliases::U256;
 
 #[account]
 #[derive(InitSpace)]
 pub struct UserPosition {
-    pub user: Pubkey,          // User's wallet address
-    pub strategy: Pubkey,      // Reference to the StrategyConfig
-    pub strategy_share: u64,   // User's share of the strategy position
-    pub last_share_value: u64, // Last share value when user deposited/withdrew
-    pub last_update_slot: u64, // Last slot the position was updated
-    pub bump: u8,              // PDA bump
+    pub version: u8,            // Layout version, see migrate_* instructions
+    pub user: Pubkey,           // User's wallet address
+    pub strategy: Pubkey,       // Reference to the StrategyConfig
+    pub strategy_share: u64,    // User's share of the strategy position
+    pub last_share_value: u128, // Average share value the user holds the shares at
+    pub last_update_slot: u64,  // Last slot the position was updated
+    pub bump: u8,               // PDA bump
+    pub reserved: [u8; 64],     // Space for fields added in later versions
 }
 
 impl UserPosition {
     pub const SEED_PREFIX: &'static str = "user-position";
+    pub const VERSION: u8 = 1;
 
     pub fn get_pda_signer<'a>(self: &'a Self) -> [&'a [u8]; 4] {
         let prefix_bytes = Self::SEED_PREFIX.as_bytes();
@@ -32,6 +39,7 @@ impl UserPosition {
         slot: u64,
         bump: u8,
     ) {
+        self.version = Self::VERSION;
         self.user = user;
         self.strategy = strategy;
         self.strategy_share = shares;
@@ -40,38 +48,57 @@ impl UserPosition {
         self.bump = bump;
     }
 
-    /// Calculate performance fee if the share value has increased. Returns the performance fee shares to be deducted
-    pub fn calculate_performance_fee_shares(
-        &self,
-        current_share_value: u64,
-        performance_fee_bps: u16,
-    ) -> Result<u64> {
-        // If no value gain, no fee
-        if current_share_value <= self.last_share_value {
-            return Ok(0);
+    /// Sync the tracked shares with the user's share token balance, which changes when share tokens are transferred.
+    /// Received shares have an unknown entry value, so they are assigned the current share value. The performance
+    /// fee is charged on the strategy share value, so the entry value only reports the user's gains.
+    pub fn sync_share_balance(
+        &mut self,
+        share_balance: u64,
+        current_share_value: u128,
+    ) -> Result<()> {
+        if share_balance > self.strategy_share {
+            let received_shares = share_balance
+                .checked_sub(self.strategy_share)
+                .ok_or(MaikerError::ArithmeticOverflow)?;
+
+            self.receive_shares(received_shares, current_share_value)?;
         }
 
-        // Calculate value gain percentage (in basis points)
-        let value_gain_bps = current_share_value
-            .checked_sub(self.last_share_value)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_mul(10000)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_div(self.last_share_value)
-            .ok_or(MaikerError::ArithmeticOverflow)?;
+        // Sent shares leave with the tracked share value
+        self.strategy_share = share_balance;
+
+        Ok(())
+    }
 
-        msg!("Value gain bps: {}", value_gain_bps);
+    /// Add shares received from another position, carrying over their share value
+    pub fn receive_shares(&mut self, shares_amount: u64, share_value: u128) -> Result<()> {
+        let share_balance = self
+            .strategy_share
+            .checked_add(shares_amount)
+            .ok_or(MaikerError::ArithmeticOverflow)?;
+        if share_balance == 0 {
+            return Ok(());
+        }
 
-        // Calculate performance fee shares
-        let performance_fee_shares = (self.strategy_share as u128)
-            .checked_mul(value_gain_bps as u128)
+        // Weighted average of the tracked share value and the share value of the received shares, rounded down
+        let total_value = U256::from(self.strategy_share)
+            .checked_mul(U256::from(self.last_share_value))
             .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_mul(performance_fee_bps as u128)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_div(100_000_000 as u128)
+            .checked_add(
+                U256::from(shares_amount)
+                    .checked_mul(U256::from(share_value))
+                    .ok_or(MaikerError::ArithmeticOverflow)?,
+            )
             .ok_or(MaikerError::ArithmeticOverflow)?;
 
-        Ok(performance_fee_shares as u64) // TODO: Check if this is correct. This may be on the value not on the shares itself
+        self.last_share_value = total_value
+            .checked_div(U256::from(share_balance))
+            .ok_or(MaikerError::ArithmeticOverflow)?
+            .try_into()
+            .map_err(|_| MaikerError::ArithmeticOverflow)?;
+        self.strategy_share = share_balance;
+
+        Ok(())
     }
 
     /// Calculate withdrawal fees. Returns the withdrawal fee shares to be deducted
@@ -84,55 +111,96 @@ impl UserPosition {
             return Ok(0);
         }
 
-        let withdrawal_fee_shares = shares_amount
-            .checked_mul(withdrawal_fee_bps as u64)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_div(10_000)
-            .ok_or(MaikerError::ArithmeticOverflow)?;
-
-        Ok(withdrawal_fee_shares)
+        // Rounded up, so splitting a withdrawal can not avoid the fee
+        safe_mul_div_cast(
+            shares_amount as u128,
+            withdrawal_fee_bps as u128,
+            BASIS_POINT_MAX as u128,
+            Rounding::Up,
+        )
     }
 
     /// Update user position after deposit
     pub fn update_after_deposit(
         &mut self,
         new_shares: u64,
-        performance_fee_shares: u64,
-        current_share_value: u64,
+        current_share_value: u128,
         slot: u64,
     ) -> Result<()> {
-        // Update shares (subtract performance fee shares and add new shares)
-        self.strategy_share = self
-            .strategy_share
-            .checked_sub(performance_fee_shares)
-            .ok_or(MaikerError::ArithmeticOverflow)?
-            .checked_add(new_shares)
-            .ok_or(MaikerError::ArithmeticOverflow)?;
-
-        // Update last share value and timestamp
-        self.last_share_value = current_share_value;
+        // The new shares are held at the current share value
+        self.receive_shares(new_shares, current_share_value)?;
         self.last_update_slot = slot;
 
         Ok(())
     }
 
     /// Update user position after withdrawal
-    pub fn update_after_withdrawal(
-        &mut self,
-        shares_amount: u64,
-        current_share_value: u64,
-        slot: u64,
-    ) -> Result<()> {
-        // Subtract withdrawn shares
+    pub fn update_after_withdrawal(&mut self, shares_amount: u64, slot: u64) -> Result<()> {
+        // Subtract withdrawn shares, the remaining shares keep their share value
         self.strategy_share = self
             .strategy_share
             .checked_sub(shares_amount)
             .ok_or(MaikerError::ArithmeticOverflow)?;
-
-        // Update last share value and timestamp
-        self.last_share_value = current_share_value;
         self.last_update_slot = slot;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn user_position(strategy_share: u64, last_share_value: u128) -> UserPosition {
+        let mut position =
+            UserPosition::deserialize(&mut &[0u8; UserPosition::INIT_SPACE][..]).unwrap();
+        position.strategy_share = strategy_share;
+        position.last_share_value = last_share_value;
+        position
+    }
+
+    #[test]
+    fn weights_received_share_value_by_size() {
+        let mut position = user_position(300, SHARE_PRECISION);
+
+        position.receive_shares(100, 5 * SHARE_PRECISION).unwrap();
+        assert_eq!(position.strategy_share, 400);
+        assert_eq!(position.last_share_value, 2 * SHARE_PRECISION);
+
+        // Shares received by token transfer are assigned the current share value
+        position
+            .sync_share_balance(800, SHARE_PRECISION / 2)
+            .unwrap();
+        assert_eq!(position.strategy_share, 800);
+        assert_eq!(position.last_share_value, 5 * SHARE_PRECISION / 4);
+
+        // Sent shares leave the tracked share value unchanged
+        position
+            .sync_share_balance(200, 4 * SHARE_PRECISION)
+            .unwrap();
+        assert_eq!(position.strategy_share, 200);
+        assert_eq!(position.last_share_value, 5 * SHARE_PRECISION / 4);
+    }
+
+    proptest! {
+        /// The withdrawal fee is the exact reference fee rounded up
+        #[test]
+        fn withdrawal_fee_rounds_up(
+            shares_amount in 0u64..u64::MAX,
+            withdrawal_fee_bps in 0u16..=10_000,
+        ) {
+            let position = user_position(shares_amount, SHARE_PRECISION);
+
+            let fee_shares = position
+                .calculate_withdrawal_fee_shares(shares_amount, withdrawal_fee_bps)
+                .unwrap() as u128;
+
+            let fee_value = shares_amount as u128 * withdrawal_fee_bps as u128;
+            prop_assert!(fee_shares * BASIS_POINT_MAX as u128 >= fee_value);
+            if fee_shares > 0 {
+                prop_assert!((fee_shares - 1) * (BASIS_POINT_MAX as u128) < fee_value);
+            }
+        }
+    }
+}